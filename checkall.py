@@ -33,9 +33,9 @@ def check(package: str, feature_list: list[str]):
 def main():
     os.environ["QUICKCHECK_TESTS"] = "100000"
 
-    check("dxr", ["derive", "multicall", "i8", "nil", "chrono", "jiff", "time"])
+    check("dxr", ["derive", "multicall", "json", "i8", "nil", "chrono", "jiff", "time"])
     check("dxr_derive", [])
-    check("dxr_client", ["default", "multicall", "reqwest", "default-tls", "native-tls", "rustls-tls"])
+    check("dxr_client", ["default", "multicall", "batching", "reqwest", "default-tls", "native-tls", "rustls-tls"])
     check("dxr_server", ["default", "multicall", "axum"])
     check("dxr_tests", [])
 
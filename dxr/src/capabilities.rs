@@ -0,0 +1,209 @@
+//! capabilities that are reported by the `system.getCapabilities` method
+
+use std::collections::BTreeMap;
+
+use crate::error::DxrError;
+use crate::traits::{TryFromValue, TryToValue};
+use crate::values::{Member, Struct, Value};
+
+/// Specification of a capability (or extension of XML-RPC) that is supported by a server
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Capability {
+    spec_url: String,
+    spec_version: i32,
+}
+
+impl Capability {
+    /// Construct a new [`Capability`] from the URL and version of its specification.
+    pub fn new(spec_url: String, spec_version: i32) -> Capability {
+        Capability { spec_url, spec_version }
+    }
+
+    /// Retrieve the URL of the specification.
+    pub fn spec_url(&self) -> &str {
+        &self.spec_url
+    }
+
+    /// Retrieve the version of the specification.
+    pub fn spec_version(&self) -> i32 {
+        self.spec_version
+    }
+}
+
+/// Capabilities are represented as `<struct>` values with `specUrl` and `specVersion` members.
+/// Any additional members are ignored.
+impl TryFromValue for Capability {
+    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
+        let mut spec_url = None;
+        let mut spec_version = None;
+
+        for (name, value) in value.struct_members()? {
+            match name {
+                "specUrl" => spec_url = Some(String::try_from_value(value)?),
+                "specVersion" => spec_version = Some(i32::try_from_value(value)?),
+                _ => {},
+            }
+        }
+
+        Ok(Capability {
+            spec_url: spec_url.ok_or_else(|| DxrError::missing_field("Capability", "specUrl"))?,
+            spec_version: spec_version.ok_or_else(|| DxrError::missing_field("Capability", "specVersion"))?,
+        })
+    }
+}
+
+impl TryToValue for Capability {
+    fn try_to_value(&self) -> Result<Value, DxrError> {
+        Ok(Value::structure(Struct::new(vec![
+            Member::new(String::from("specUrl"), Value::string(self.spec_url.clone())),
+            Member::new(String::from("specVersion"), Value::i4(self.spec_version)),
+        ])))
+    }
+}
+
+/// Capabilities of an XML-RPC server, as reported by the `system.getCapabilities` method
+///
+/// This is a mapping of capability names (i.e. [`Capabilities::MULTICALL`]) to the
+/// [`Capability`] with the specification of the capability.
+///
+/// ```
+/// use dxr::Capabilities;
+///
+/// let capabilities = Capabilities::new();
+/// assert!(capabilities.supports(Capabilities::XMLRPC));
+/// assert!(!capabilities.supports(Capabilities::MULTICALL));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Capabilities {
+    capabilities: BTreeMap<String, Capability>,
+}
+
+impl Capabilities {
+    /// name of the capability for the XML-RPC specification
+    pub const XMLRPC: &'static str = "xmlrpc";
+    /// name of the capability for the specification for fault code interoperability
+    pub const FAULTS_INTEROP: &'static str = "faults_interop";
+    /// name of the capability for the introspection API
+    pub const INTROSPECTION: &'static str = "introspection";
+    /// name of the capability for "system.multicall" calls
+    pub const MULTICALL: &'static str = "multicall";
+    /// name of the capability for the non-standard `<nil/>` value type
+    pub const NIL: &'static str = "nil";
+    /// name of the capability for the non-standard `<i8>` value type
+    pub const I8: &'static str = "i8";
+
+    /// Construct a new set of [`Capabilities`] with the capabilities of this crate.
+    ///
+    /// This always includes the XML-RPC specification itself, and the `nil` and `i8` extensions
+    /// if the respective features of this crate are enabled. Server-specific capabilities (like
+    /// "system.multicall" support) can be added with [`Capabilities::add`].
+    pub fn new() -> Capabilities {
+        let capabilities = Capabilities::empty().add(
+            Capabilities::XMLRPC,
+            Capability::new(String::from("http://www.xmlrpc.com/spec"), 1),
+        );
+
+        #[cfg(feature = "nil")]
+        let capabilities = capabilities.add(
+            Capabilities::NIL,
+            Capability::new(String::from("http://www.ontosys.com/xml-rpc/extensions.php"), 20010518),
+        );
+
+        #[cfg(feature = "i8")]
+        let capabilities = capabilities.add(
+            Capabilities::I8,
+            Capability::new(String::from("http://ws.apache.org/xmlrpc/types.html"), 1),
+        );
+
+        capabilities
+    }
+
+    /// Construct a new empty set of [`Capabilities`].
+    pub fn empty() -> Capabilities {
+        Capabilities {
+            capabilities: BTreeMap::new(),
+        }
+    }
+
+    /// Add a capability (replacing any existing capability with the same name).
+    pub fn add(mut self, name: &str, capability: Capability) -> Capabilities {
+        self.capabilities.insert(name.to_owned(), capability);
+        self
+    }
+
+    /// Add the capability for the specification for fault code interoperability.
+    pub fn faults_interop(self) -> Capabilities {
+        self.add(
+            Capabilities::FAULTS_INTEROP,
+            Capability::new(
+                String::from("http://xmlrpc-epi.sourceforge.net/specs/rfc.fault_codes.php"),
+                20010516,
+            ),
+        )
+    }
+
+    /// Add the capability for the introspection API.
+    pub fn introspection(self) -> Capabilities {
+        self.add(
+            Capabilities::INTROSPECTION,
+            Capability::new(
+                String::from("http://xmlrpc-epi.sourceforge.net/specs/rfc.introspection.php"),
+                20010516,
+            ),
+        )
+    }
+
+    /// Add the capability for "system.multicall" calls.
+    pub fn multicall(self) -> Capabilities {
+        self.add(
+            Capabilities::MULTICALL,
+            Capability::new(String::from("http://www.xmlrpc.com/discuss/msgReader$1208"), 1),
+        )
+    }
+
+    /// Check whether a capability is supported.
+    pub fn supports(&self, name: &str) -> bool {
+        self.capabilities.contains_key(name)
+    }
+
+    /// Retrieve the specification of a capability (if it is supported).
+    pub fn get(&self, name: &str) -> Option<&Capability> {
+        self.capabilities.get(name)
+    }
+
+    /// Iterate over the names and specifications of all capabilities (sorted by name).
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Capability)> {
+        self.capabilities
+            .iter()
+            .map(|(name, capability)| (name.as_str(), capability))
+    }
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Capabilities::new()
+    }
+}
+
+impl TryFromValue for Capabilities {
+    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
+        let capabilities = value
+            .struct_members()?
+            .map(|(name, value)| Ok((name.to_owned(), Capability::try_from_value(value)?)))
+            .collect::<Result<BTreeMap<String, Capability>, DxrError>>()?;
+
+        Ok(Capabilities { capabilities })
+    }
+}
+
+impl TryToValue for Capabilities {
+    fn try_to_value(&self) -> Result<Value, DxrError> {
+        let members = self
+            .capabilities
+            .iter()
+            .map(|(name, capability)| Ok(Member::new(name.clone(), capability.try_to_value()?)))
+            .collect::<Result<Vec<Member>, DxrError>>()?;
+
+        Ok(Value::structure(Struct::new(members)))
+    }
+}
@@ -21,6 +21,14 @@ pub enum DxrError {
         /// name of the missing struct field
         field: Cow<'static, str>,
     },
+    /// Error variant for a struct field that is present more than once.
+    #[error("Struct '{}' has duplicate field: {}", .name, .field)]
+    DuplicateField {
+        /// name of the struct that has a duplicate field
+        name: Cow<'static, str>,
+        /// name of the duplicate struct field
+        field: Cow<'static, str>,
+    },
     #[error("Parameter mismatch: got {} values, expected {}", .argument, .expected)]
     /// Error variant for mismatch with an expected number of values.
     ParameterMismatch {
@@ -29,6 +37,14 @@ pub enum DxrError {
         /// number of expected values
         expected: usize,
     },
+    /// Error variant for an invalid or missing method call parameter.
+    #[error("Invalid parameter '{}': {}", .name, .error)]
+    InvalidParameter {
+        /// name of the invalid parameter
+        name: Cow<'static, str>,
+        /// underlying conversion error
+        error: Box<DxrError>,
+    },
     /// Error variant for mismatch with an expected value type.
     #[error("Type mismatch: got {}, expected {}", .argument, .expected)]
     WrongType {
@@ -85,6 +101,30 @@ impl DxrError {
         }
     }
 
+    /// Construct a [`DxrError`] for a duplicate struct field.
+    pub fn duplicate_field(name: &'static str, field: &'static str) -> DxrError {
+        DxrError::DuplicateField {
+            name: Cow::Borrowed(name),
+            field: Cow::Borrowed(field),
+        }
+    }
+
+    /// Check if a given [`DxrError`] was raised for a duplicate struct field.
+    pub fn is_duplicate_field(&self) -> bool {
+        matches!(self, DxrError::DuplicateField { .. })
+    }
+
+    /// Check for [`DxrError::DuplicateField`] and return the inner error in case of a match.
+    ///
+    /// The returned value is a tuple of (struct name, duplicate field name).
+    pub fn as_duplicate_field(&self) -> Option<(&str, &str)> {
+        if let DxrError::DuplicateField { name, field } = self {
+            Some((name, field))
+        } else {
+            None
+        }
+    }
+
     /// Construct a [`DxrError`] for a parameter number mismatch.
     pub fn parameter_mismatch(argument: usize, expected: usize) -> DxrError {
         DxrError::ParameterMismatch { argument, expected }
@@ -106,6 +146,30 @@ impl DxrError {
         }
     }
 
+    /// Construct a [`DxrError`] for an invalid or missing method call parameter.
+    pub fn invalid_parameter(name: &'static str, error: DxrError) -> DxrError {
+        DxrError::InvalidParameter {
+            name: Cow::Borrowed(name),
+            error: Box::new(error),
+        }
+    }
+
+    /// Check if a given [`DxrError`] was raised for an invalid or missing method call parameter.
+    pub fn is_invalid_parameter(&self) -> bool {
+        matches!(self, DxrError::InvalidParameter { .. })
+    }
+
+    /// Check for [`DxrError::InvalidParameter`] and return the inner error in case of a match.
+    ///
+    /// The returned value is a tuple of (parameter name, underlying error).
+    pub fn as_invalid_parameter(&self) -> Option<(&str, &DxrError)> {
+        if let DxrError::InvalidParameter { name, error } = self {
+            Some((name, error))
+        } else {
+            None
+        }
+    }
+
     /// Construct a [`DxrError`] for a type mismatch.
     pub fn wrong_type(argument: &'static str, expected: &'static str) -> DxrError {
         DxrError::WrongType {
@@ -131,13 +195,20 @@ impl DxrError {
     }
 }
 
+/// Errors are converted into faults with the [`Fault::INVALID_PARAMS`] code, since they usually
+/// occur when converting method call parameters.
+///
+/// Servers can use a different mapping for the errors that they detect themselves (for example,
+/// errors in the conversion of return values of typed method handlers are server errors).
 impl From<DxrError> for Fault {
     fn from(error: DxrError) -> Self {
         match error {
-            DxrError::InvalidData { .. } => Fault::new(400, error.to_string()),
-            DxrError::MissingField { .. } => Fault::new(400, error.to_string()),
-            DxrError::ParameterMismatch { .. } => Fault::new(400, error.to_string()),
-            DxrError::WrongType { .. } => Fault::new(400, error.to_string()),
+            DxrError::InvalidData { .. } => Fault::new(Fault::INVALID_PARAMS, error.to_string()),
+            DxrError::MissingField { .. } => Fault::new(Fault::INVALID_PARAMS, error.to_string()),
+            DxrError::DuplicateField { .. } => Fault::new(Fault::INVALID_PARAMS, error.to_string()),
+            DxrError::ParameterMismatch { .. } => Fault::new(Fault::INVALID_PARAMS, error.to_string()),
+            DxrError::InvalidParameter { .. } => Fault::new(Fault::INVALID_PARAMS, error.to_string()),
+            DxrError::WrongType { .. } => Fault::new(Fault::INVALID_PARAMS, error.to_string()),
         }
     }
 }
@@ -1,24 +1,66 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
 use thiserror::Error;
 
 use crate::error::DxrError;
-use crate::traits::TryFromValue;
-use crate::values::FaultResponse;
+use crate::traits::{TryFromValue, TryToValue};
+use crate::values::{FaultResponse, Member, Struct, Value};
 
 /// XML-RPC server fault (consisting of a numeric error code and a message)
 ///
-/// *Note*: There are no standardized numeric error codes, and they will likely be
-/// specific to the server application.
-#[derive(Clone, Debug, Eq, Error, PartialEq)]
+/// *Note*: The XML-RPC specification does not define any numeric error codes, and they will likely
+/// be specific to the server application. However, many implementations use the codes that are
+/// defined in the [specification for fault code interoperability][interop], which are available
+/// as associated constants (i.e. [`Fault::METHOD_NOT_FOUND`]).
+///
+/// [interop]: http://xmlrpc-epi.sourceforge.net/specs/rfc.fault_codes.php
+#[derive(Clone, Debug, Error, PartialEq)]
 #[error("Server Fault {}: {}", .code, .string)]
 pub struct Fault {
     code: i32,
     string: String,
+    members: BTreeMap<String, Value>,
 }
 
 impl Fault {
+    /// parse error: the request is not well-formed XML
+    pub const PARSE_ERROR: i32 = -32700;
+    /// parse error: the request uses an unsupported character encoding
+    pub const UNSUPPORTED_ENCODING: i32 = -32701;
+    /// parse error: the request contains an invalid character for its encoding
+    pub const INVALID_CHARACTER: i32 = -32702;
+    /// server error: the request is not a valid XML-RPC request
+    pub const INVALID_REQUEST: i32 = -32600;
+    /// server error: the requested method does not exist
+    pub const METHOD_NOT_FOUND: i32 = -32601;
+    /// server error: the parameters are invalid for the requested method
+    pub const INVALID_PARAMS: i32 = -32602;
+    /// server error: internal XML-RPC error
+    pub const INTERNAL_ERROR: i32 = -32603;
+    /// application error
+    pub const APPLICATION_ERROR: i32 = -32500;
+    /// system error
+    pub const SYSTEM_ERROR: i32 = -32400;
+    /// transport error
+    pub const TRANSPORT_ERROR: i32 = -32300;
+
     /// Construct a new [`Fault`] from numeric error code and an error message.
     pub fn new(code: i32, string: String) -> Fault {
-        Fault { code, string }
+        Fault {
+            code,
+            string,
+            members: BTreeMap::new(),
+        }
+    }
+
+    /// Attach an additional struct member to the [`Fault`] (i.e. `faultDetail`).
+    ///
+    /// Members named `faultCode` or `faultString` are ignored when the fault is converted into a
+    /// value or a fault response.
+    pub fn with_member(mut self, name: String, value: Value) -> Fault {
+        self.members.insert(name, value);
+        self
     }
 
     /// Retrieve the numeric error code from the [`Fault`].
@@ -30,34 +72,107 @@ impl Fault {
     pub fn string(&self) -> &str {
         self.string.as_str()
     }
+
+    /// Retrieve the additional struct members of the [`Fault`] (other than `faultCode` and
+    /// `faultString`), sorted by name.
+    pub fn members(&self) -> &BTreeMap<String, Value> {
+        &self.members
+    }
+
+    /// Retrieve an additional struct member of the [`Fault`] and convert it into a Rust value.
+    ///
+    /// Missing members are handled like missing struct fields in derived [`TryFromValue`]
+    /// implementations, i.e. they are only accepted for types like [`Option<T>`].
+    ///
+    /// ```
+    /// use dxr::{Fault, Value};
+    ///
+    /// let fault = Fault::new(1, String::from("Error"))
+    ///     .with_member(String::from("faultDetail"), Value::string(String::from("Traceback")));
+    ///
+    /// let detail: String = fault.member("faultDetail").unwrap();
+    /// assert_eq!(detail, "Traceback");
+    /// let missing: Option<String> = fault.member("traceback").unwrap();
+    /// assert_eq!(missing, None);
+    /// ```
+    pub fn member<T>(&self, name: &str) -> Result<T, DxrError>
+    where
+        T: TryFromValue,
+    {
+        match self.members.get(name) {
+            Some(value) => T::try_from_value(value),
+            None => T::try_from_missing().ok_or_else(|| DxrError::MissingField {
+                name: Cow::Borrowed("Fault"),
+                field: Cow::Owned(name.to_owned()),
+            }),
+        }
+    }
+
+    pub(crate) fn to_struct(&self) -> Struct {
+        let mut members = vec![
+            Member::new(String::from("faultCode"), Value::i4(self.code)),
+            Member::new(String::from("faultString"), Value::string(self.string.clone())),
+        ];
+
+        for (name, value) in &self.members {
+            if name != "faultCode" && name != "faultString" {
+                members.push(Member::new(name.clone(), value.clone()));
+            }
+        }
+
+        Struct::new(members)
+    }
 }
 
-impl TryFrom<FaultResponse> for Fault {
-    type Error = DxrError;
+// fault codes are sometimes sent as strings instead of integers
+fn fault_code(value: &Value) -> Result<i32, DxrError> {
+    i32::try_from_value(value).or_else(|error| match String::try_from_value(value) {
+        Ok(string) => string
+            .trim()
+            .parse()
+            .map_err(|_| DxrError::invalid_data(format!("Invalid fault code: {string}"))),
+        Err(_) => Err(error),
+    })
+}
 
-    fn try_from(value: FaultResponse) -> Result<Self, Self::Error> {
-        let mut members = value.members().iter();
+/// Faults are represented as `<struct>` values with `faultCode` and `faultString` members. Any
+/// additional members are preserved, and fault codes are also accepted as strings (i.e.
+/// `<string>4</string>`), as long as they contain an integer.
+impl TryFromValue for Fault {
+    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
+        let mut code = None;
+        let mut string = None;
+        let mut members = BTreeMap::new();
+
+        for (name, value) in value.struct_members()? {
+            match name {
+                "faultCode" if code.is_some() => return Err(DxrError::duplicate_field("Fault", "faultCode")),
+                "faultCode" => code = Some(fault_code(value)?),
+                "faultString" if string.is_some() => return Err(DxrError::duplicate_field("Fault", "faultString")),
+                "faultString" => string = Some(String::try_from_value(value)?),
+                _ => {
+                    members.insert(name.to_owned(), value.clone());
+                },
+            }
+        }
 
-        let (first, second) = match (members.next(), members.next(), members.next()) {
-            (Some(first), Some(second), None) => (first, second),
-            _ => return Err(DxrError::parameter_mismatch(members.len(), 2)),
-        };
+        let code = code.ok_or_else(|| DxrError::missing_field("Fault", "faultCode"))?;
+        let string = string.ok_or_else(|| DxrError::missing_field("Fault", "faultString"))?;
 
-        let fault_code = if first.name() == "faultCode" {
-            first.inner()
-        } else {
-            return Err(DxrError::missing_field("fault", "faultCode"));
-        };
+        Ok(Fault { code, string, members })
+    }
+}
 
-        let fault_string = if second.name() == "faultString" {
-            second.inner()
-        } else {
-            return Err(DxrError::missing_field("fault", "faultString"));
-        };
+impl TryToValue for Fault {
+    fn try_to_value(&self) -> Result<Value, DxrError> {
+        Ok(Value::structure(self.to_struct()))
+    }
+}
 
-        let code: i32 = i32::try_from_value(fault_code)?;
-        let string: String = String::try_from_value(fault_string)?;
+impl TryFrom<FaultResponse> for Fault {
+    type Error = DxrError;
 
-        Ok(Fault::new(code, string))
+    fn try_from(value: FaultResponse) -> Result<Self, Self::Error> {
+        Fault::try_from_value(&value.into_value())
     }
 }
@@ -1,7 +1,10 @@
+mod borrow_value;
 mod from_value;
 mod to_value;
 
 mod from_params;
 mod to_params;
 
+mod xmlrpc_type;
+
 mod utils;
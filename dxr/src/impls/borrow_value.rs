@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use crate::error::DxrError;
+use crate::traits::{TryBorrowFromValue, TryFromValue};
+use crate::values::{Type, Value};
+
+impl<'a, T> TryBorrowFromValue<'a> for T
+where
+    T: TryFromValue,
+{
+    fn try_borrow_from_value(value: &'a Value) -> Result<Self, DxrError> {
+        T::try_from_value(value)
+    }
+
+    fn try_borrow_from_missing() -> Option<Self> {
+        T::try_from_missing()
+    }
+}
+
+impl<'a: 'b, 'b> TryBorrowFromValue<'a> for &'b Value {
+    fn try_borrow_from_value(value: &'a Value) -> Result<Self, DxrError> {
+        Ok(value)
+    }
+}
+
+impl<'a: 'b, 'b> TryBorrowFromValue<'a> for &'b str {
+    fn try_borrow_from_value(value: &'a Value) -> Result<Self, DxrError> {
+        match value.inner() {
+            Type::String(string) => Ok(string.as_str()),
+            t => Err(DxrError::wrong_type(t.name(), "string")),
+        }
+    }
+}
+
+impl<'a: 'b, 'b> TryBorrowFromValue<'a> for &'b [u8] {
+    fn try_borrow_from_value(value: &'a Value) -> Result<Self, DxrError> {
+        match value.inner() {
+            Type::Base64(bytes) => Ok(bytes.as_slice()),
+            t => Err(DxrError::wrong_type(t.name(), "base64")),
+        }
+    }
+}
+
+impl<'a: 'b, 'b, T> TryBorrowFromValue<'a> for HashMap<&'b str, T>
+where
+    T: TryBorrowFromValue<'a>,
+{
+    fn try_borrow_from_value(value: &'a Value) -> Result<Self, DxrError> {
+        let values = match value.inner() {
+            Type::Struct { members } => Ok(members),
+            t => Err(DxrError::wrong_type(t.name(), "struct")),
+        };
+
+        values?
+            .iter()
+            .map(|v| Ok((v.name(), T::try_borrow_from_value(v.inner())?)))
+            .collect()
+    }
+}
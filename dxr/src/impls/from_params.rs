@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
 use crate::error::DxrError;
+use crate::params::Rest;
 use crate::traits::{TryFromParams, TryFromValue};
 use crate::values::{DateTime, Value};
 
-use super::utils::*;
+use super::utils::{for_all_tuples, TupleFromValues};
 
 // for simple values, just call the impls for singletons / one-tuples
 
@@ -93,7 +94,6 @@ impl TryFromParams for Vec<u8> {
 // - check whether there *is* a value
 // - check whether it is a <nil> value
 
-#[cfg(feature = "nil")]
 impl<T> TryFromParams for Option<T>
 where
     T: TryFromValue,
@@ -119,6 +119,15 @@ where
     }
 }
 
+impl<T> TryFromParams for Rest<T>
+where
+    T: TryFromValue,
+{
+    fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
+        Ok(Rest(Vec::try_from_params(values)?))
+    }
+}
+
 impl TryFromParams for () {
     fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
         match values.len() {
@@ -142,104 +151,30 @@ where
 
 // treat tuples as collections of values of different types
 
-impl<T> TryFromParams for (T,)
-where
-    T: TryFromValue,
-{
-    fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
-        values_to_tuple_1(values)
-    }
-}
-
-impl<A, B> TryFromParams for (A, B)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-{
-    fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
-        values_to_tuple_2(values)
-    }
-}
-
-impl<A, B, C> TryFromParams for (A, B, C)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-{
-    fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
-        values_to_tuple_3(values)
-    }
-}
-
-impl<A, B, C, D> TryFromParams for (A, B, C, D)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-    D: TryFromValue,
-{
-    fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
-        values_to_tuple_4(values)
-    }
-}
-
-impl<A, B, C, D, E> TryFromParams for (A, B, C, D, E)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-    D: TryFromValue,
-    E: TryFromValue,
-{
-    fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
-        values_to_tuple_5(values)
-    }
-}
-
-impl<A, B, C, D, E, F> TryFromParams for (A, B, C, D, E, F)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-    D: TryFromValue,
-    E: TryFromValue,
-    F: TryFromValue,
-{
-    fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
-        values_to_tuple_6(values)
-    }
-}
-
-impl<A, B, C, D, E, F, G> TryFromParams for (A, B, C, D, E, F, G)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-    D: TryFromValue,
-    E: TryFromValue,
-    F: TryFromValue,
-    G: TryFromValue,
-{
-    fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
-        values_to_tuple_7(values)
-    }
-}
+macro_rules! tuple_from_params {
+    ($len:literal; $($t:ident $v:ident),+) => {
+        impl<$($t),+> TryFromParams for ($($t,)+)
+        where
+            $($t: TryFromValue,)+
+        {
+            fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
+                TupleFromValues::tuple_from_params(values)
+            }
+        }
 
-impl<A, B, C, D, E, F, G, H> TryFromParams for (A, B, C, D, E, F, G, H)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-    D: TryFromValue,
-    E: TryFromValue,
-    F: TryFromValue,
-    G: TryFromValue,
-    H: TryFromValue,
-{
-    fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
-        values_to_tuple_8(values)
-    }
+        // fixed leading parameters, followed by any number of trailing parameters
+        impl<$($t,)+ T> TryFromParams for ($($t,)+ Rest<T>)
+        where
+            $($t: TryFromValue,)+
+            T: TryFromValue,
+        {
+            fn try_from_params(values: &[Value]) -> Result<Self, DxrError> {
+                let ($($v,)+) = TupleFromValues::tuple_from_params(values.get(..$len).unwrap_or(values))?;
+                let rest = Rest::try_from_params(values.get($len..).unwrap_or_default())?;
+                Ok(($($v,)+ rest))
+            }
+        }
+    };
 }
 
-// if needed, implementations for more arguments can be implemented
+for_all_tuples!(tuple_from_params);
@@ -4,10 +4,11 @@ use std::rc::Rc;
 use std::sync::Arc;
 
 use crate::error::DxrError;
+use crate::params::Defaulted;
 use crate::traits::TryFromValue;
 use crate::values::{DateTime, Type, Value};
 
-use super::utils::*;
+use super::utils::{for_all_tuples, TupleFromValues};
 
 impl TryFromValue for Value {
     fn try_from_value(value: &Value) -> Result<Value, DxrError> {
@@ -109,17 +110,34 @@ impl TryFromValue for Vec<u8> {
     }
 }
 
-#[cfg(feature = "nil")]
 impl<T> TryFromValue for Option<T>
 where
     T: TryFromValue,
 {
     fn try_from_value(value: &Value) -> Result<Option<T>, DxrError> {
+        #[cfg(feature = "nil")]
         if let Type::Nil = value.inner() {
-            Ok(None)
-        } else {
-            Ok(Some(T::try_from_value(value)?))
+            return Ok(None);
         }
+
+        Ok(Some(T::try_from_value(value)?))
+    }
+
+    fn try_from_missing() -> Option<Self> {
+        Some(None)
+    }
+}
+
+impl<T> TryFromValue for Defaulted<T>
+where
+    T: TryFromValue + Default,
+{
+    fn try_from_value(value: &Value) -> Result<Defaulted<T>, DxrError> {
+        Ok(Defaulted(T::try_from_value(value)?))
+    }
+
+    fn try_from_missing() -> Option<Self> {
+        Some(Defaulted(T::default()))
     }
 }
 
@@ -242,144 +260,22 @@ impl TryFromValue for () {
     }
 }
 
-impl<T> TryFromValue for (T,)
-where
-    T: TryFromValue,
-{
-    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
-        if let Type::Array { data } = value.inner() {
-            let values = data.inner();
-            values_to_tuple_1(values)
-        } else {
-            Err(DxrError::wrong_type(value.inner().name(), "array"))
-        }
-    }
-}
-
-impl<A, B> TryFromValue for (A, B)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-{
-    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
-        if let Type::Array { data } = value.inner() {
-            let values = data.inner();
-            values_to_tuple_2(values)
-        } else {
-            Err(DxrError::wrong_type(value.inner().name(), "array"))
-        }
-    }
-}
-
-impl<A, B, C> TryFromValue for (A, B, C)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-{
-    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
-        if let Type::Array { data } = value.inner() {
-            let values = data.inner();
-            values_to_tuple_3(values)
-        } else {
-            Err(DxrError::wrong_type(value.inner().name(), "array"))
-        }
-    }
-}
-
-impl<A, B, C, D> TryFromValue for (A, B, C, D)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-    D: TryFromValue,
-{
-    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
-        if let Type::Array { data } = value.inner() {
-            let values = data.inner();
-            values_to_tuple_4(values)
-        } else {
-            Err(DxrError::wrong_type(value.inner().name(), "array"))
-        }
-    }
-}
-
-impl<A, B, C, D, E> TryFromValue for (A, B, C, D, E)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-    D: TryFromValue,
-    E: TryFromValue,
-{
-    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
-        if let Type::Array { data } = value.inner() {
-            let values = data.inner();
-            values_to_tuple_5(values)
-        } else {
-            Err(DxrError::wrong_type(value.inner().name(), "array"))
-        }
-    }
-}
-
-impl<A, B, C, D, E, F> TryFromValue for (A, B, C, D, E, F)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-    D: TryFromValue,
-    E: TryFromValue,
-    F: TryFromValue,
-{
-    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
-        if let Type::Array { data } = value.inner() {
-            let values = data.inner();
-            values_to_tuple_6(values)
-        } else {
-            Err(DxrError::wrong_type(value.inner().name(), "array"))
-        }
-    }
-}
-
-impl<A, B, C, D, E, F, G> TryFromValue for (A, B, C, D, E, F, G)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-    D: TryFromValue,
-    E: TryFromValue,
-    F: TryFromValue,
-    G: TryFromValue,
-{
-    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
-        if let Type::Array { data } = value.inner() {
-            let values = data.inner();
-            values_to_tuple_7(values)
-        } else {
-            Err(DxrError::wrong_type(value.inner().name(), "array"))
-        }
-    }
-}
-
-impl<A, B, C, D, E, F, G, H> TryFromValue for (A, B, C, D, E, F, G, H)
-where
-    A: TryFromValue,
-    B: TryFromValue,
-    C: TryFromValue,
-    D: TryFromValue,
-    E: TryFromValue,
-    F: TryFromValue,
-    G: TryFromValue,
-    H: TryFromValue,
-{
-    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
-        if let Type::Array { data } = value.inner() {
-            let values = data.inner();
-            values_to_tuple_8(values)
-        } else {
-            Err(DxrError::wrong_type(value.inner().name(), "array"))
+macro_rules! tuple_from_value {
+    ($len:literal; $($t:ident $v:ident),+) => {
+        impl<$($t),+> TryFromValue for ($($t,)+)
+        where
+            $($t: TryFromValue,)+
+        {
+            fn try_from_value(value: &Value) -> Result<Self, DxrError> {
+                if let Type::Array { data } = value.inner() {
+                    let values = data.inner();
+                    TupleFromValues::tuple_from_values(values)
+                } else {
+                    Err(DxrError::wrong_type(value.inner().name(), "array"))
+                }
+            }
         }
-    }
+    };
 }
 
-// if needed, implementations for more arguments can be implemented
+for_all_tuples!(tuple_from_value);
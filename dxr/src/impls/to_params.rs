@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
 use crate::error::DxrError;
+use crate::params::Rest;
 use crate::traits::{TryToParams, TryToValue};
 use crate::values::{DateTime, Value};
 
-use super::utils::*;
+use super::utils::{for_all_tuples, TupleToValues};
 
 // for simple values, use TryToValue to convert them
 
@@ -151,6 +152,15 @@ where
     }
 }
 
+impl<T> TryToParams for Rest<T>
+where
+    T: TryToValue,
+{
+    fn try_to_params(&self) -> Result<Vec<Value>, DxrError> {
+        self.0.try_to_params()
+    }
+}
+
 // treat maps as a single value of a struct
 
 impl<T> TryToParams for HashMap<String, T>
@@ -179,104 +189,31 @@ impl TryToParams for () {
     }
 }
 
-impl<T> TryToParams for (T,)
-where
-    T: TryToValue,
-{
-    fn try_to_params(&self) -> Result<Vec<Value>, DxrError> {
-        tuple_to_values_1(self)
-    }
-}
-
-impl<A, B> TryToParams for (A, B)
-where
-    A: TryToValue,
-    B: TryToValue,
-{
-    fn try_to_params(&self) -> Result<Vec<Value>, DxrError> {
-        tuple_to_values_2(self)
-    }
-}
-
-impl<A, B, C> TryToParams for (A, B, C)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-{
-    fn try_to_params(&self) -> Result<Vec<Value>, DxrError> {
-        tuple_to_values_3(self)
-    }
-}
-
-impl<A, B, C, D> TryToParams for (A, B, C, D)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-    D: TryToValue,
-{
-    fn try_to_params(&self) -> Result<Vec<Value>, DxrError> {
-        tuple_to_values_4(self)
-    }
-}
-
-impl<A, B, C, D, E> TryToParams for (A, B, C, D, E)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-    D: TryToValue,
-    E: TryToValue,
-{
-    fn try_to_params(&self) -> Result<Vec<Value>, DxrError> {
-        tuple_to_values_5(self)
-    }
-}
-
-impl<A, B, C, D, E, F> TryToParams for (A, B, C, D, E, F)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-    D: TryToValue,
-    E: TryToValue,
-    F: TryToValue,
-{
-    fn try_to_params(&self) -> Result<Vec<Value>, DxrError> {
-        tuple_to_values_6(self)
-    }
-}
-
-impl<A, B, C, D, E, F, G> TryToParams for (A, B, C, D, E, F, G)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-    D: TryToValue,
-    E: TryToValue,
-    F: TryToValue,
-    G: TryToValue,
-{
-    fn try_to_params(&self) -> Result<Vec<Value>, DxrError> {
-        tuple_to_values_7(self)
-    }
-}
-
-impl<A, B, C, D, E, F, G, H> TryToParams for (A, B, C, D, E, F, G, H)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-    D: TryToValue,
-    E: TryToValue,
-    F: TryToValue,
-    G: TryToValue,
-    H: TryToValue,
-{
-    fn try_to_params(&self) -> Result<Vec<Value>, DxrError> {
-        tuple_to_values_8(self)
-    }
-}
-
-// if needed, implementations for more arguments can be implemented
+macro_rules! tuple_to_params {
+    ($len:literal; $($t:ident $v:ident),+) => {
+        impl<$($t),+> TryToParams for ($($t,)+)
+        where
+            $($t: TryToValue,)+
+        {
+            fn try_to_params(&self) -> Result<Vec<Value>, DxrError> {
+                self.tuple_to_values()
+            }
+        }
+
+        // fixed leading parameters, followed by any number of trailing parameters
+        impl<$($t,)+ T> TryToParams for ($($t,)+ Rest<T>)
+        where
+            $($t: TryToValue,)+
+            T: TryToValue,
+        {
+            fn try_to_params(&self) -> Result<Vec<Value>, DxrError> {
+                let ($($v,)+ rest) = self;
+                let mut params = vec![$($v.try_to_value()?),+];
+                params.extend(rest.try_to_params()?);
+                Ok(params)
+            }
+        }
+    };
+}
+
+for_all_tuples!(tuple_to_params);
@@ -4,10 +4,11 @@ use std::rc::Rc;
 use std::sync::Arc;
 
 use crate::error::DxrError;
+use crate::params::Defaulted;
 use crate::traits::TryToValue;
 use crate::values::{Array, DateTime, Member, Struct, Value};
 
-use super::utils::*;
+use super::utils::{for_all_tuples, TupleToValues};
 
 impl<T> TryToValue for &T
 where
@@ -120,6 +121,15 @@ where
     }
 }
 
+impl<T> TryToValue for Defaulted<T>
+where
+    T: TryToValue,
+{
+    fn try_to_value(&self) -> Result<Value, DxrError> {
+        T::try_to_value(&self.0)
+    }
+}
+
 impl TryToValue for Cow<'_, str> {
     fn try_to_value(&self) -> Result<Value, DxrError> {
         match self {
@@ -228,104 +238,17 @@ where
     }
 }
 
-impl<T> TryToValue for (T,)
-where
-    T: TryToValue,
-{
-    fn try_to_value(&self) -> Result<Value, DxrError> {
-        Ok(Value::array(Array::new(tuple_to_values_1(self)?)))
-    }
-}
-
-impl<A, B> TryToValue for (A, B)
-where
-    A: TryToValue,
-    B: TryToValue,
-{
-    fn try_to_value(&self) -> Result<Value, DxrError> {
-        Ok(Value::array(Array::new(tuple_to_values_2(self)?)))
-    }
-}
-
-impl<A, B, C> TryToValue for (A, B, C)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-{
-    fn try_to_value(&self) -> Result<Value, DxrError> {
-        Ok(Value::array(Array::new(tuple_to_values_3(self)?)))
-    }
-}
-
-impl<A, B, C, D> TryToValue for (A, B, C, D)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-    D: TryToValue,
-{
-    fn try_to_value(&self) -> Result<Value, DxrError> {
-        Ok(Value::array(Array::new(tuple_to_values_4(self)?)))
-    }
-}
-
-impl<A, B, C, D, E> TryToValue for (A, B, C, D, E)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-    D: TryToValue,
-    E: TryToValue,
-{
-    fn try_to_value(&self) -> Result<Value, DxrError> {
-        Ok(Value::array(Array::new(tuple_to_values_5(self)?)))
-    }
-}
-
-impl<A, B, C, D, E, F> TryToValue for (A, B, C, D, E, F)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-    D: TryToValue,
-    E: TryToValue,
-    F: TryToValue,
-{
-    fn try_to_value(&self) -> Result<Value, DxrError> {
-        Ok(Value::array(Array::new(tuple_to_values_6(self)?)))
-    }
-}
-
-impl<A, B, C, D, E, F, G> TryToValue for (A, B, C, D, E, F, G)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-    D: TryToValue,
-    E: TryToValue,
-    F: TryToValue,
-    G: TryToValue,
-{
-    fn try_to_value(&self) -> Result<Value, DxrError> {
-        Ok(Value::array(Array::new(tuple_to_values_7(self)?)))
-    }
-}
-
-impl<A, B, C, D, E, F, G, H> TryToValue for (A, B, C, D, E, F, G, H)
-where
-    A: TryToValue,
-    B: TryToValue,
-    C: TryToValue,
-    D: TryToValue,
-    E: TryToValue,
-    F: TryToValue,
-    G: TryToValue,
-    H: TryToValue,
-{
-    fn try_to_value(&self) -> Result<Value, DxrError> {
-        Ok(Value::array(Array::new(tuple_to_values_8(self)?)))
-    }
+macro_rules! tuple_to_value {
+    ($len:literal; $($t:ident $v:ident),+) => {
+        impl<$($t),+> TryToValue for ($($t,)+)
+        where
+            $($t: TryToValue,)+
+        {
+            fn try_to_value(&self) -> Result<Value, DxrError> {
+                Ok(Value::array(Array::new(self.tuple_to_values()?)))
+            }
+        }
+    };
 }
 
-// if needed, implementations for more arguments can be implemented
+for_all_tuples!(tuple_to_value);
@@ -0,0 +1,371 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::error::DxrError;
+use crate::params::{Defaulted, Rest};
+use crate::traits::{XmlRpcParams, XmlRpcType};
+use crate::values::{DateTime, Value};
+
+use super::utils::for_all_tuples;
+
+impl<T> XmlRpcType for &T
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_type() -> &'static str {
+        T::xmlrpc_type()
+    }
+
+    fn accepts(value: &Value) -> bool {
+        T::accepts(value)
+    }
+}
+
+// values can have any type
+impl XmlRpcType for Value {
+    fn xmlrpc_type() -> &'static str {
+        "any"
+    }
+
+    fn accepts(_value: &Value) -> bool {
+        true
+    }
+}
+
+impl XmlRpcType for i32 {
+    fn xmlrpc_type() -> &'static str {
+        "i4"
+    }
+}
+
+#[cfg(feature = "i8")]
+impl XmlRpcType for i64 {
+    fn xmlrpc_type() -> &'static str {
+        "i8"
+    }
+}
+
+impl XmlRpcType for bool {
+    fn xmlrpc_type() -> &'static str {
+        "boolean"
+    }
+}
+
+impl XmlRpcType for String {
+    fn xmlrpc_type() -> &'static str {
+        "string"
+    }
+}
+
+impl XmlRpcType for &str {
+    fn xmlrpc_type() -> &'static str {
+        "string"
+    }
+}
+
+impl XmlRpcType for Cow<'_, str> {
+    fn xmlrpc_type() -> &'static str {
+        "string"
+    }
+}
+
+impl XmlRpcType for f64 {
+    fn xmlrpc_type() -> &'static str {
+        "double"
+    }
+}
+
+impl XmlRpcType for DateTime {
+    fn xmlrpc_type() -> &'static str {
+        "dateTime.iso8601"
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl XmlRpcType for chrono::NaiveDateTime {
+    fn xmlrpc_type() -> &'static str {
+        "dateTime.iso8601"
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl XmlRpcType for jiff::civil::DateTime {
+    fn xmlrpc_type() -> &'static str {
+        "dateTime.iso8601"
+    }
+}
+
+#[cfg(feature = "time")]
+impl XmlRpcType for time::PrimitiveDateTime {
+    fn xmlrpc_type() -> &'static str {
+        "dateTime.iso8601"
+    }
+}
+
+impl XmlRpcType for Vec<u8> {
+    fn xmlrpc_type() -> &'static str {
+        "base64"
+    }
+}
+
+impl<const N: usize> XmlRpcType for [u8; N] {
+    fn xmlrpc_type() -> &'static str {
+        "base64"
+    }
+}
+
+impl XmlRpcType for &[u8] {
+    fn xmlrpc_type() -> &'static str {
+        "base64"
+    }
+}
+
+// optional values have the type of their inner value, but can also be nil
+impl<T> XmlRpcType for Option<T>
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_type() -> &'static str {
+        T::xmlrpc_type()
+    }
+
+    fn accepts(value: &Value) -> bool {
+        #[cfg(feature = "nil")]
+        if value.type_name() == "nil" {
+            return true;
+        }
+
+        T::accepts(value)
+    }
+}
+
+impl<T> XmlRpcType for Defaulted<T>
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_type() -> &'static str {
+        T::xmlrpc_type()
+    }
+
+    fn accepts(value: &Value) -> bool {
+        T::accepts(value)
+    }
+}
+
+impl<T> XmlRpcType for Cow<'_, T>
+where
+    T: XmlRpcType + Clone,
+{
+    fn xmlrpc_type() -> &'static str {
+        T::xmlrpc_type()
+    }
+
+    fn accepts(value: &Value) -> bool {
+        T::accepts(value)
+    }
+}
+
+impl<T> XmlRpcType for Box<T>
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_type() -> &'static str {
+        T::xmlrpc_type()
+    }
+
+    fn accepts(value: &Value) -> bool {
+        T::accepts(value)
+    }
+}
+
+impl<T> XmlRpcType for Rc<T>
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_type() -> &'static str {
+        T::xmlrpc_type()
+    }
+
+    fn accepts(value: &Value) -> bool {
+        T::accepts(value)
+    }
+}
+
+impl<T> XmlRpcType for Arc<T>
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_type() -> &'static str {
+        T::xmlrpc_type()
+    }
+
+    fn accepts(value: &Value) -> bool {
+        T::accepts(value)
+    }
+}
+
+impl<T> XmlRpcType for Vec<T>
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_type() -> &'static str {
+        "array"
+    }
+}
+
+impl<T, const N: usize> XmlRpcType for [T; N]
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_type() -> &'static str {
+        "array"
+    }
+}
+
+impl<T> XmlRpcType for &[T]
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_type() -> &'static str {
+        "array"
+    }
+}
+
+impl<T> XmlRpcType for HashMap<String, T>
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_type() -> &'static str {
+        "struct"
+    }
+}
+
+impl<T> XmlRpcType for HashMap<&str, T>
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_type() -> &'static str {
+        "struct"
+    }
+}
+
+impl XmlRpcParams for () {
+    fn xmlrpc_params() -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn check_params(values: &[Value]) -> Result<(), DxrError> {
+        match values {
+            [] => Ok(()),
+            _ => Err(DxrError::parameter_mismatch(values.len(), 0)),
+        }
+    }
+}
+
+// lists of homogeneously typed parameters have variable length, which cannot be described by
+// signatures, so only the types of leading parameters are listed
+
+impl<T> XmlRpcParams for Vec<T>
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_params() -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn check_params(values: &[Value]) -> Result<(), DxrError> {
+        check_rest::<T>(values, 0)
+    }
+}
+
+// Rest<T> only describes lists of parameters and is not a value, so it does not implement
+// XmlRpcType (which would also conflict with the implementations for tuples)
+impl<T> XmlRpcParams for Rest<T>
+where
+    T: XmlRpcType,
+{
+    fn xmlrpc_params() -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn check_params(values: &[Value]) -> Result<(), DxrError> {
+        check_rest::<T>(values, 0)
+    }
+}
+
+/// check the type of the parameter at the given (zero-based) index
+fn check_param<T: XmlRpcType>(values: &[Value], index: usize) -> Result<(), DxrError> {
+    match values.get(index) {
+        Some(value) if !T::accepts(value) => Err(DxrError::InvalidParameter {
+            name: Cow::Owned((index + 1).to_string()),
+            error: Box::new(DxrError::wrong_type(value.type_name(), T::xmlrpc_type())),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// check the types of all parameters, starting at the given (zero-based) index
+fn check_rest<T: XmlRpcType>(values: &[Value], start: usize) -> Result<(), DxrError> {
+    (start..values.len()).try_for_each(|index| check_param::<T>(values, index))
+}
+
+// tuples are represented as arrays, but they also describe lists of parameters
+
+macro_rules! tuple_xmlrpc_type {
+    ($len:literal; $($t:ident $v:ident),+) => {
+        impl<$($t),+> XmlRpcType for ($($t,)+)
+        where
+            $($t: XmlRpcType,)+
+        {
+            fn xmlrpc_type() -> &'static str {
+                "array"
+            }
+        }
+
+        impl<$($t),+> XmlRpcParams for ($($t,)+)
+        where
+            $($t: XmlRpcType,)+
+        {
+            fn xmlrpc_params() -> Vec<&'static str> {
+                vec![$($t::xmlrpc_type()),+]
+            }
+
+            fn check_params(values: &[Value]) -> Result<(), DxrError> {
+                if values.len() > $len {
+                    return Err(DxrError::parameter_mismatch(values.len(), $len));
+                }
+
+                let checks: [fn(&[Value], usize) -> Result<(), DxrError>; $len] = [$(check_param::<$t>),+];
+                for (index, check) in checks.iter().enumerate() {
+                    check(values, index)?;
+                }
+
+                Ok(())
+            }
+        }
+
+        // fixed leading parameters, followed by any number of trailing parameters
+        impl<$($t,)+ T> XmlRpcParams for ($($t,)+ Rest<T>)
+        where
+            $($t: XmlRpcType,)+
+            T: XmlRpcType,
+        {
+            fn xmlrpc_params() -> Vec<&'static str> {
+                vec![$($t::xmlrpc_type()),+]
+            }
+
+            fn check_params(values: &[Value]) -> Result<(), DxrError> {
+                let checks: [fn(&[Value], usize) -> Result<(), DxrError>; $len] = [$(check_param::<$t>),+];
+                for (index, check) in checks.iter().enumerate() {
+                    check(values, index)?;
+                }
+
+                check_rest::<T>(values, $len)
+            }
+        }
+    };
+}
+
+for_all_tuples!(tuple_xmlrpc_type);
@@ -0,0 +1,275 @@
+//! conversion between XML-RPC values and JSON values from the `serde_json` crate
+
+use serde_json::{Map, Number};
+
+use crate::base64;
+use crate::error::DxrError;
+use crate::values::{Array, Member, Struct, Type, Value};
+
+/// Representation of `<base64>` values in JSON
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum JsonBase64 {
+    /// base64-encoded string (the same encoding that is used in XML-RPC)
+    #[default]
+    String,
+    /// array of numbers (one per byte)
+    Array,
+}
+
+/// Handling of JSON integers that do not fit into the supported XML-RPC integer types
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum JsonIntegerOverflow {
+    /// fail with an error
+    #[default]
+    Error,
+    /// convert to `<double>` (which can lose precision)
+    Double,
+}
+
+/// Representation of `<dateTime.iso8601>` values in JSON
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum JsonDateTime {
+    /// string in the XML-RPC date & time format (i.e. `20240101T12:00:00`)
+    #[default]
+    XmlRpc,
+    /// string in the extended ISO 8601 format (i.e. `2024-01-01T12:00:00`)
+    Iso8601,
+}
+
+/// Handling of the distinction between integer and floating-point numbers
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum JsonNumbers {
+    /// integers are converted into `<i4>` / `<i8>` values, and floating-point numbers (i.e. JSON
+    /// numbers with a fractional part or exponent, like `1.0`) into `<double>` values
+    #[default]
+    Exact,
+    /// all numbers are converted into `<double>` values, and all integer values into JSON
+    /// floating-point numbers
+    Double,
+}
+
+/// Handling of JSON `null` values
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum JsonNull {
+    /// convert into `<nil/>` values (fails with an error if the `nil` feature is not enabled)
+    #[default]
+    Nil,
+    /// omit struct members with `null` values (fails with an error for other `null` values)
+    Omit,
+    /// fail with an error
+    Error,
+}
+
+/// Options for converting between XML-RPC values and JSON values.
+///
+/// Not all XML-RPC values have a direct JSON equivalent, so the conversion is lossy. The default
+/// settings are listed first, and can be changed with the methods of this type:
+///
+/// - `<dateTime.iso8601>` values are converted into strings in the XML-RPC date & time format, or
+///   in the extended ISO 8601 format ([`JsonOptions::datetime`]). JSON strings are always
+///   converted into `<string>` values, so date & time values do not survive a round trip.
+/// - `<base64>` values are converted into base64-encoded strings, or arrays of numbers
+///   ([`JsonOptions::base64`]). Like date & time values, they are converted back into `<string>`
+///   or `<array>` values.
+/// - `<i4>` / `<i8>` values are converted into JSON integers, and `<double>` values into JSON
+///   floating-point numbers. In the other direction, JSON integers are converted into `<i4>`
+///   values (or `<i8>` values if the `i8` feature is enabled and the number does not fit into an
+///   `<i4>` value), and JSON floating-point numbers into `<double>` values. All numbers can be
+///   treated as floating-point numbers instead ([`JsonOptions::numbers`]). JSON integers that are
+///   out of range result in an error, or are converted into `<double>` values
+///   ([`JsonOptions::integer_overflow`]).
+/// - `<nil/>` values are converted into `null`. In the other direction, `null` is converted into
+///   `<nil/>` values (which requires the `nil` feature), or struct members with `null` values are
+///   omitted, or `null` values result in an error ([`JsonOptions::null`]).
+///
+/// Non-finite `<double>` values (NaN and infinity) cannot be represented in JSON, and result in an
+/// error.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonOptions {
+    base64: JsonBase64,
+    datetime: JsonDateTime,
+    numbers: JsonNumbers,
+    integer_overflow: JsonIntegerOverflow,
+    null: JsonNull,
+}
+
+impl JsonOptions {
+    /// Constructor for [`JsonOptions`] with default settings.
+    pub fn new() -> Self {
+        JsonOptions::default()
+    }
+
+    /// Method for setting the JSON representation of `<base64>` values.
+    pub fn base64(mut self, base64: JsonBase64) -> Self {
+        self.base64 = base64;
+        self
+    }
+
+    /// Method for setting the JSON representation of `<dateTime.iso8601>` values.
+    pub fn datetime(mut self, datetime: JsonDateTime) -> Self {
+        self.datetime = datetime;
+        self
+    }
+
+    /// Method for setting how integer and floating-point numbers are distinguished.
+    pub fn numbers(mut self, numbers: JsonNumbers) -> Self {
+        self.numbers = numbers;
+        self
+    }
+
+    /// Method for setting how JSON integers that are out of range are handled.
+    pub fn integer_overflow(mut self, integer_overflow: JsonIntegerOverflow) -> Self {
+        self.integer_overflow = integer_overflow;
+        self
+    }
+
+    /// Method for setting how JSON `null` values are handled.
+    pub fn null(mut self, null: JsonNull) -> Self {
+        self.null = null;
+        self
+    }
+
+    /// Convert an XML-RPC value into a JSON value.
+    pub fn value_to_json(&self, value: &Value) -> Result<serde_json::Value, DxrError> {
+        let json = match value.inner() {
+            Type::Integer(int) => match self.numbers {
+                JsonNumbers::Exact => serde_json::Value::from(*int),
+                JsonNumbers::Double => serde_json::Value::from(f64::from(*int)),
+            },
+            #[cfg(feature = "i8")]
+            Type::Long(long) => match self.numbers {
+                JsonNumbers::Exact => serde_json::Value::from(*long),
+                JsonNumbers::Double => serde_json::Value::from(*long as f64),
+            },
+            Type::Boolean(boolean) => serde_json::Value::Bool(*boolean),
+            Type::String(string) => serde_json::Value::String(string.clone()),
+            Type::Double(double) => match Number::from_f64(*double) {
+                Some(number) => serde_json::Value::Number(number),
+                None => {
+                    return Err(DxrError::invalid_data(format!(
+                        "Non-finite double cannot be represented in JSON: {double}"
+                    )))
+                },
+            },
+            Type::DateTime(datetime) => match self.datetime {
+                JsonDateTime::XmlRpc => serde_json::Value::String(datetime.to_string()),
+                JsonDateTime::Iso8601 => serde_json::Value::String(format!(
+                    "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                    datetime.year(),
+                    datetime.month(),
+                    datetime.day(),
+                    datetime.hour(),
+                    datetime.minute(),
+                    datetime.second(),
+                )),
+            },
+            Type::Base64(bytes) => match self.base64 {
+                JsonBase64::String => serde_json::Value::String(base64::encode(bytes)),
+                JsonBase64::Array => serde_json::Value::Array(bytes.iter().map(|byte| (*byte).into()).collect()),
+            },
+            Type::Struct { members } => serde_json::Value::Object(
+                members
+                    .iter()
+                    .map(|member| Ok((member.name().to_owned(), self.value_to_json(member.inner())?)))
+                    .collect::<Result<Map<String, serde_json::Value>, DxrError>>()?,
+            ),
+            Type::Array { data } => serde_json::Value::Array(
+                data.inner()
+                    .iter()
+                    .map(|value| self.value_to_json(value))
+                    .collect::<Result<Vec<serde_json::Value>, DxrError>>()?,
+            ),
+            #[cfg(feature = "nil")]
+            Type::Nil => serde_json::Value::Null,
+        };
+
+        Ok(json)
+    }
+
+    /// Convert a JSON value into an XML-RPC value.
+    pub fn json_to_value(&self, json: &serde_json::Value) -> Result<Value, DxrError> {
+        let value = match json {
+            #[cfg(feature = "nil")]
+            serde_json::Value::Null if self.null == JsonNull::Nil => Value::nil(),
+            serde_json::Value::Null => return Err(DxrError::wrong_type("null", "value")),
+            serde_json::Value::Bool(boolean) => Value::boolean(*boolean),
+            serde_json::Value::Number(number) => self.number_to_value(number)?,
+            serde_json::Value::String(string) => Value::string(string.clone()),
+            serde_json::Value::Array(array) => Value::array(Array::new(
+                array
+                    .iter()
+                    .map(|json| self.json_to_value(json))
+                    .collect::<Result<Vec<Value>, DxrError>>()?,
+            )),
+            serde_json::Value::Object(object) => Value::structure(Struct::new(
+                object
+                    .iter()
+                    .filter(|(_, json)| !(json.is_null() && self.null == JsonNull::Omit))
+                    .map(|(name, json)| Ok(Member::new(name.clone(), self.json_to_value(json)?)))
+                    .collect::<Result<Vec<Member>, DxrError>>()?,
+            )),
+        };
+
+        Ok(value)
+    }
+
+    fn number_to_value(&self, number: &Number) -> Result<Value, DxrError> {
+        if number.is_f64() || self.numbers == JsonNumbers::Double {
+            if let Some(double) = number.as_f64() {
+                return Ok(Value::double(double));
+            }
+        }
+
+        if let Some(int) = number.as_i64().and_then(|long| i32::try_from(long).ok()) {
+            return Ok(Value::i4(int));
+        }
+
+        #[cfg(feature = "i8")]
+        if let Some(long) = number.as_i64() {
+            return Ok(Value::i8(long));
+        }
+
+        match (self.integer_overflow, number.as_f64()) {
+            (JsonIntegerOverflow::Double, Some(double)) => Ok(Value::double(double)),
+            _ => Err(DxrError::invalid_data(format!(
+                "JSON integer is out of range for XML-RPC values: {number}"
+            ))),
+        }
+    }
+}
+
+/// Conversion with the default [`JsonOptions`].
+impl TryFrom<&Value> for serde_json::Value {
+    type Error = DxrError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        JsonOptions::default().value_to_json(value)
+    }
+}
+
+/// Conversion with the default [`JsonOptions`].
+impl TryFrom<Value> for serde_json::Value {
+    type Error = DxrError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        JsonOptions::default().value_to_json(&value)
+    }
+}
+
+/// Conversion with the default [`JsonOptions`].
+impl TryFrom<&serde_json::Value> for Value {
+    type Error = DxrError;
+
+    fn try_from(json: &serde_json::Value) -> Result<Self, Self::Error> {
+        JsonOptions::default().json_to_value(json)
+    }
+}
+
+/// Conversion with the default [`JsonOptions`].
+impl TryFrom<serde_json::Value> for Value {
+    type Error = DxrError;
+
+    fn try_from(json: serde_json::Value) -> Result<Self, Self::Error> {
+        JsonOptions::default().json_to_value(&json)
+    }
+}
@@ -21,7 +21,7 @@
 //! | `boolean`          | [`bool`]                  |
 //! | `string`           | [`String`] / [`&str`]     |
 //! | `double`           | [`f64`]                   |
-//! | `dateTime.iso8601` | [`chrono::NaiveDateTime`] |
+//! | `dateTime.iso8601` | [`DateTime`]              |
 //! | `base64`           | [`Vec<u8>`]               |
 //! | `nil`              | [`Option<T>`]             |
 //!
@@ -31,16 +31,30 @@
 //! - [`Vec<T>`], slices `&[T]`, and fixed-size arrays `[T; N]`,
 //! - smart pointer types like [`Box<T>`], [`Cow<T>`], [`Rc<T>`], and [`Arc<T>`],
 //! - mappings like [`HashMap<String, T>`] / [`HashMap<&str, T>`],
-//! - tuples `(T, ...)` with up to eight members
+//! - tuples `(T, ...)` with up to sixteen members
 //!
 //! (as long as the inner type `T` also implement these traits).
 //!
+//! Method call parameters can also be converted from and to tuples with up to sixteen leading
+//! members followed by a [`Rest<T>`], which collects any number of trailing parameters.
+//!
+//! The names of the XML-RPC value types that correspond to these Rust types are available with the
+//! [`XmlRpcType`] trait (i.e. for publishing method signatures with the introspection API).
+//!
+//! Types that borrow from XML-RPC values (like `&str` for `string` values and `&[u8]` for `base64`
+//! values) can be converted with the [`TryBorrowFromValue`] trait instead.
+//!
 //! ## Features
 //!
 //! This crate provides optional features, all of which are disabled by default:
 //!
-//! - `derive`: include procedural macros for deriving the [`TryFromValue`] and [`TryToValue`]
-//!   traits for custom structs
+//! - `derive`: include procedural macros for deriving the [`TryFromValue`], [`TryBorrowFromValue`],
+//!   and [`TryToValue`] traits for custom structs, and the [`TryFromParams`] and [`TryToParams`]
+//!   traits for structs that represent lists of method call parameters, and the [`IntoFault`] and
+//!   [`TryFromFault`] traits for error enums that are mapped onto XML-RPC faults (the
+//!   [`XmlRpcType`] and [`XmlRpcParams`] traits can be derived for custom structs, too)
+//! - `json`: conversion between XML-RPC values and JSON values from the `serde_json` crate (see
+//!   `JsonOptions` for details)
 //! - `i8`: enable support for the non-standard `i8` value type
 //! - `nil`: enable support for the non-standard `nil` value type
 
@@ -48,11 +62,23 @@
 #[cfg(doc)]
 use std::{borrow::Cow, collections::HashMap, rc::Rc, sync::Arc};
 
+// re-export encoding_rs, as it is exposed in the public API
+pub use encoding_rs;
+
+// re-export the deserialization error type of quick-xml, as it is exposed in the public API
+pub use quick_xml::de::DeError;
+
 #[cfg(feature = "derive")]
-pub use dxr_derive::{TryFromValue, TryToValue};
+pub use dxr_derive::{
+    IntoFault, TryBorrowFromValue, TryFromFault, TryFromParams, TryFromValue, TryToParams, TryToValue, XmlRpcParams,
+    XmlRpcType,
+};
 
 mod base64;
 
+mod capabilities;
+pub use capabilities::{Capabilities, Capability};
+
 mod error;
 pub use error::*;
 
@@ -61,14 +87,29 @@ pub use fault::*;
 
 mod impls;
 
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+pub use json::*;
+
+mod macros;
+#[doc(hidden)]
+pub use macros::__private;
+
 #[cfg(feature = "multicall")]
 mod multicall;
 #[cfg(feature = "multicall")]
 pub use multicall::*;
 
+mod params;
+pub use params::{Defaulted, Rest};
+
 mod traits;
 pub use traits::*;
 
+mod validation;
+pub use validation::Validation;
+
 mod values;
 pub use values::*;
 
@@ -0,0 +1,163 @@
+//! declarative macros for constructing XML-RPC values and method call parameters
+
+/// Construct a [`Value`](crate::Value) from a JSON-like literal.
+///
+/// The following syntax is supported:
+///
+/// - `[...]`: `<array>` values with a comma-separated list of items
+/// - `{"name": ..., ...}`: `<struct>` values with a comma-separated list of members (member names
+///   must be string literals)
+/// - `nil`: the `<nil/>` value (requires the `nil` feature)
+/// - any other expression: converted into a value with its [`TryToValue`](crate::TryToValue)
+///   implementation
+///
+/// Arrays and structs can be nested arbitrarily. The macro evaluates to a
+/// `Result<Value, DxrError>`, which contains the first error that occurred while converting
+/// expressions into values (if any).
+///
+/// ```
+/// use dxr::{value, DateTime, Value};
+///
+/// let dt: DateTime = "20240101T12:00:00".parse().unwrap();
+/// let ids = vec![1, 2, 3];
+///
+/// let value: Value = value!({
+///     "name": "foo",
+///     "ids": [1, 2, 3],
+///     "other": ids,
+///     "when": dt,
+///     "nested": {"enabled": true, "ratio": 0.5},
+/// })
+/// .unwrap();
+/// ```
+#[macro_export]
+macro_rules! value {
+    // arrays: all items have been parsed
+    (@array [$($items:expr,)*]) => {
+        <::std::vec::Vec<$crate::Value>>::from([$($items,)*])
+    };
+
+    // arrays: next item is nil, an array, or a struct
+    (@array [$($items:expr,)*] nil $(, $($rest:tt)*)?) => {
+        $crate::value!(@array [$($items,)* $crate::value!(@value nil),] $($($rest)*)?)
+    };
+    (@array [$($items:expr,)*] [$($array:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::value!(@array [$($items,)* $crate::value!(@value [$($array)*]),] $($($rest)*)?)
+    };
+    (@array [$($items:expr,)*] {$($members:tt)*} $(, $($rest:tt)*)?) => {
+        $crate::value!(@array [$($items,)* $crate::value!(@value {$($members)*}),] $($($rest)*)?)
+    };
+
+    // arrays: next item is any other expression
+    (@array [$($items:expr,)*] $next:expr $(, $($rest:tt)*)?) => {
+        $crate::value!(@array [$($items,)* $crate::value!(@value $next),] $($($rest)*)?)
+    };
+
+    // structs: all members have been parsed
+    (@struct [$($members:expr,)*]) => {
+        $crate::__private::structure(::std::vec![$($members,)*])
+    };
+
+    // structs: next member value is nil, an array, or a struct
+    (@struct [$($members:expr,)*] $name:literal : nil $(, $($rest:tt)*)?) => {
+        $crate::value!(@struct [$($members,)* ($name, $crate::value!(@value nil)),] $($($rest)*)?)
+    };
+    (@struct [$($members:expr,)*] $name:literal : [$($array:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::value!(@struct [$($members,)* ($name, $crate::value!(@value [$($array)*])),] $($($rest)*)?)
+    };
+    (@struct [$($members:expr,)*] $name:literal : {$($inner:tt)*} $(, $($rest:tt)*)?) => {
+        $crate::value!(@struct [$($members,)* ($name, $crate::value!(@value {$($inner)*})),] $($($rest)*)?)
+    };
+
+    // structs: next member value is any other expression
+    (@struct [$($members:expr,)*] $name:literal : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::value!(@struct [$($members,)* ($name, $crate::value!(@value $value)),] $($($rest)*)?)
+    };
+
+    // values (conversion errors are propagated with the ? operator)
+    (@value nil) => {
+        $crate::Value::nil()
+    };
+    (@value [$($items:tt)*]) => {
+        $crate::__private::array($crate::value!(@array [] $($items)*))
+    };
+    (@value {$($members:tt)*}) => {
+        $crate::value!(@struct [] $($members)*)
+    };
+    (@value $other:expr) => {
+        $crate::__private::to_value(&$other)?
+    };
+
+    ($($value:tt)+) => {
+        $crate::__private::build(|| ::std::result::Result::Ok($crate::value!(@value $($value)+)))
+    };
+}
+
+/// Construct a list of method call parameters from JSON-like literals.
+///
+/// This macro accepts a comma-separated list of values with the same syntax as the [`value!`]
+/// macro, and evaluates to a `Result<Vec<Value>, DxrError>`. The list of values can be passed to
+/// [`MethodCall::new`] or used as the arguments of a method call with a client.
+///
+/// ```
+/// use dxr::{params, MethodCall};
+///
+/// let call = MethodCall::new("add", params![1, 2, {"round": true}].unwrap());
+/// ```
+///
+/// [`MethodCall::new`]: crate::MethodCall::new
+#[macro_export]
+macro_rules! params {
+    ($($items:tt)*) => {
+        $crate::__private::build(|| ::std::result::Result::Ok($crate::value!(@array [] $($items)*)))
+    };
+}
+
+// implementation details of the value! and params! macros (and of derived TryToParams impls)
+#[doc(hidden)]
+pub mod __private {
+    use crate::error::DxrError;
+    use crate::traits::TryToValue;
+    use crate::values::{Array, Member, Struct, Value};
+
+    pub fn array(items: Vec<Value>) -> Value {
+        Value::array(Array::new(items))
+    }
+
+    pub fn structure(members: Vec<(&str, Value)>) -> Value {
+        Value::structure(Struct::new(
+            members
+                .into_iter()
+                .map(|(name, value)| Member::new(String::from(name), value))
+                .collect(),
+        ))
+    }
+
+    pub fn to_value<T>(value: &T) -> Result<Value, DxrError>
+    where
+        T: TryToValue,
+    {
+        value.try_to_value()
+    }
+
+    // evaluate the expansion of a macro (which can return early with the ? operator)
+    pub fn build<T>(f: impl FnOnce() -> Result<T, DxrError>) -> Result<T, DxrError> {
+        f()
+    }
+
+    /// placeholder for an omitted optional parameter, which can only be encoded as a `<nil/>` value
+    /// if it is followed by other parameters (and results in an error without the `nil` feature)
+    pub fn nil_param(name: &'static str) -> Result<Value, DxrError> {
+        #[cfg(feature = "nil")]
+        {
+            let _ = name;
+            Ok(Value::nil())
+        }
+
+        #[cfg(not(feature = "nil"))]
+        Err(DxrError::invalid_parameter(
+            name,
+            DxrError::invalid_data(String::from("Omitted parameters cannot be encoded without nil values")),
+        ))
+    }
+}
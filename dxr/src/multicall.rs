@@ -74,13 +74,7 @@ pub fn into_multicall_response(results: Vec<Result<Value, Fault>>) -> Value {
         .into_iter()
         .map(|r| match r {
             Ok(value) => Value::array(Array::new(vec![value])),
-            Err(fault) => {
-                let members = vec![
-                    Member::new(String::from("faultCode"), Value::i4(fault.code())),
-                    Member::new(String::from("faultString"), Value::string(fault.string().to_owned())),
-                ];
-                Value::structure(Struct::new(members))
-            },
+            Err(fault) => Value::structure(fault.to_struct()),
         })
         .collect();
 
@@ -0,0 +1,86 @@
+//! definitions of helper types for optional and variadic method call parameters
+
+use std::ops::{Deref, DerefMut};
+
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::{TryFromParams, TryToParams};
+
+/// Trailing method call parameters
+///
+/// This type can be used as the last member of a tuple of method call parameters to collect any
+/// number of trailing parameters, after a fixed number of leading parameters. For example, a
+/// method handler that accepts `(String, Rest<Value>)` requires at least one parameter (which
+/// needs to be a string), followed by zero or more parameters of any type.
+///
+/// Both the [`TryFromParams`] and [`TryToParams`] traits are implemented for tuples with up to
+/// sixteen leading members followed by a [`Rest<T>`], and for [`Rest<T>`] itself.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Rest<T>(pub Vec<T>);
+
+impl<T> Rest<T> {
+    /// Construct a new [`Rest`] from a list of values.
+    pub fn new(values: Vec<T>) -> Rest<T> {
+        Rest(values)
+    }
+
+    /// Consume the [`Rest`] and return the inner list of values.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> From<Vec<T>> for Rest<T> {
+    fn from(values: Vec<T>) -> Self {
+        Rest(values)
+    }
+}
+
+impl<T> Deref for Rest<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Rest<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Optional method call parameter with a default value
+///
+/// This type can be used as a trailing member of a tuple of method call parameters. If the
+/// parameter is missing, it is initialized with the [`Default`] value of the inner type, similar
+/// to how missing trailing parameters are mapped to `None` for tuple members of type [`Option<T>`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Defaulted<T>(pub T);
+
+impl<T> Defaulted<T> {
+    /// Consume the [`Defaulted`] and return the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Defaulted<T> {
+    fn from(value: T) -> Self {
+        Defaulted(value)
+    }
+}
+
+impl<T> Deref for Defaulted<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Defaulted<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -1,4 +1,14 @@
 #![allow(clippy::unwrap_used)]
 
+mod canonical;
+mod capabilities;
+#[cfg(feature = "derive")]
+mod derive;
+mod display;
 mod impls;
+#[cfg(feature = "json")]
+mod json;
+mod macros;
+mod query;
+mod visit;
 mod xml;
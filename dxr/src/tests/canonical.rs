@@ -0,0 +1,93 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashMap};
+use std::hash::{Hash, Hasher};
+
+use crate::{value, CanonicalValue, DateTime, Value};
+
+fn hash(value: &CanonicalValue) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn canonical_struct_order() {
+    let a = CanonicalValue::new(value!({"a": 1, "b": [true, "x"]}).unwrap());
+    let b = CanonicalValue::new(value!({"b": [true, "x"], "a": 1}).unwrap());
+
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+}
+
+#[test]
+fn canonical_struct_duplicates() {
+    let a = CanonicalValue::new(value!({"a": 1, "a": 2}).unwrap());
+    let b = CanonicalValue::new(value!({"a": 2, "a": 1}).unwrap());
+
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+}
+
+#[test]
+fn canonical_doubles() {
+    let nan = CanonicalValue::new(Value::double(f64::NAN));
+    assert_eq!(nan, nan.clone());
+    assert_eq!(hash(&nan), hash(&nan.clone()));
+
+    let zero = CanonicalValue::new(Value::double(0.0));
+    let neg_zero = CanonicalValue::new(Value::double(-0.0));
+    assert_ne!(zero, neg_zero);
+    assert!(neg_zero < zero);
+}
+
+#[test]
+fn canonical_types() {
+    assert_ne!(
+        CanonicalValue::new(Value::i4(1)),
+        CanonicalValue::new(Value::double(1.0))
+    );
+    assert!(CanonicalValue::new(Value::i4(100)) < CanonicalValue::new(Value::boolean(false)));
+    assert!(CanonicalValue::new(value!({}).unwrap()) < CanonicalValue::new(value!([]).unwrap()));
+}
+
+#[test]
+fn canonical_sorted() {
+    let set: BTreeSet<CanonicalValue> = [
+        value!([1, 2]).unwrap(),
+        value!("b").unwrap(),
+        value!([1]).unwrap(),
+        value!("a").unwrap(),
+        value!(2).unwrap(),
+        value!("20240101T12:00:00".parse::<DateTime>().unwrap()).unwrap(),
+        value!("20231231T12:00:00".parse::<DateTime>().unwrap()).unwrap(),
+    ]
+    .into_iter()
+    .map(CanonicalValue::new)
+    .collect();
+
+    let sorted: Vec<String> = set.iter().map(|value| value.to_string()).collect();
+    assert_eq!(
+        sorted,
+        vec![
+            "2",
+            "'a'",
+            "'b'",
+            "<DateTime '20231231T12:00:00'>",
+            "<DateTime '20240101T12:00:00'>",
+            "[1]",
+            "[1, 2]",
+        ]
+    );
+}
+
+#[test]
+fn canonical_map_key() {
+    let mut cache: HashMap<Vec<CanonicalValue>, i32> = HashMap::new();
+    let params = vec![value!("list").unwrap(), value!({"limit": 10, "offset": 0}).unwrap()];
+    cache.insert(params.into_iter().map(CanonicalValue::from).collect(), 42);
+
+    let params = vec![value!("list").unwrap(), value!({"offset": 0, "limit": 10}).unwrap()];
+    let key: Vec<CanonicalValue> = params.into_iter().map(CanonicalValue::from).collect();
+    assert_eq!(cache.get(&key), Some(&42));
+}
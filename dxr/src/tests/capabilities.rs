@@ -0,0 +1,60 @@
+//! tests for the representation of capabilities (i.e. responses for "system.getCapabilities" calls)
+
+use crate::values::{MethodResponse, Value};
+use crate::xml::deserialize_xml as from_str;
+use crate::{Capabilities, Capability, TryFromValue, TryToValue};
+
+// struct members are compared independent of their order
+#[track_caller]
+fn assert_same(left: &Value, right: &Value) {
+    let diff = left.diff(right);
+    assert!(diff.is_empty(), "{left} != {right}: {diff:?}");
+}
+
+// PHP: phpxmlrpc (response for a "system.getCapabilities" call)
+#[test]
+fn php_capabilities_response() {
+    let value = r#"<?xml version="1.0"?>
+<methodResponse>
+<params>
+<param>
+<value><struct>
+<member><name>xmlrpc</name>
+<value><struct>
+<member><name>specUrl</name>
+<value><string>http://www.xmlrpc.com/spec</string></value>
+</member>
+<member><name>specVersion</name>
+<value><int>1</int></value>
+</member>
+</struct></value>
+</member>
+<member><name>faults_interop</name>
+<value><struct>
+<member><name>specUrl</name>
+<value><string>http://xmlrpc-epi.sourceforge.net/specs/rfc.fault_codes.php</string></value>
+</member>
+<member><name>specVersion</name>
+<value><int>20010516</int></value>
+</member>
+</struct></value>
+</member>
+</struct></value>
+</param>
+</params>
+</methodResponse>"#;
+
+    let value = from_str::<MethodResponse>(value).unwrap().inner();
+    let capabilities = Capabilities::try_from_value(&value).unwrap();
+
+    assert!(capabilities.supports(Capabilities::XMLRPC));
+    assert!(!capabilities.supports(Capabilities::MULTICALL));
+    assert_eq!(
+        capabilities.get(Capabilities::FAULTS_INTEROP),
+        Some(&Capability::new(
+            String::from("http://xmlrpc-epi.sourceforge.net/specs/rfc.fault_codes.php"),
+            20010516
+        ))
+    );
+    assert_same(&capabilities.try_to_value().unwrap(), &value);
+}
@@ -0,0 +1,5 @@
+mod borrowed;
+mod fault;
+mod members;
+mod params;
+mod with;
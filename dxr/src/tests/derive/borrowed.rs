@@ -0,0 +1,117 @@
+use std::marker::PhantomData;
+
+use crate::values::{Member, Struct};
+use crate::{DxrError, TryBorrowFromValue, TryFromValue, TryToValue, Value};
+
+#[derive(Debug, PartialEq, TryBorrowFromValue, TryToValue)]
+struct Message<'a> {
+    sender: &'a str,
+    payload: &'a [u8],
+    priority: i32,
+    #[dxr(default)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, TryBorrowFromValue)]
+struct Envelope<'a> {
+    message: Message<'a>,
+    raw: &'a Value,
+}
+
+#[derive(Debug, PartialEq, TryBorrowFromValue)]
+struct Pair<'a, 'b> {
+    first: &'a str,
+    second: &'b str,
+}
+
+#[test]
+fn borrow_from_value_two_lifetimes() {
+    let value = Value::structure(Struct::new(vec![
+        Member::new(String::from("first"), Value::string(String::from("foo"))),
+        Member::new(String::from("second"), Value::string(String::from("bar"))),
+    ]));
+
+    let pair = Pair::try_borrow_from_value(&value).unwrap();
+    assert_eq!(
+        pair,
+        Pair {
+            first: "foo",
+            second: "bar"
+        }
+    );
+}
+
+#[test]
+fn borrow_from_value() {
+    let message = Message {
+        sender: "alice",
+        payload: b"hello",
+        priority: 1,
+        tags: vec![],
+    };
+    let value = message.try_to_value().unwrap();
+
+    let borrowed = Message::try_borrow_from_value(&value).unwrap();
+    assert_eq!(borrowed, message);
+}
+
+#[test]
+fn borrow_from_value_nested() {
+    let message = Message {
+        sender: "bob",
+        payload: b"world",
+        priority: 2,
+        tags: vec![String::from("urgent")],
+    };
+    let value = Value::structure(Struct::new(vec![
+        Member::new(String::from("message"), message.try_to_value().unwrap()),
+        Member::new(String::from("raw"), Value::i4(42)),
+    ]));
+
+    let envelope = Envelope::try_borrow_from_value(&value).unwrap();
+    assert_eq!(envelope.message, message);
+    assert_eq!(envelope.raw, &Value::i4(42));
+}
+
+#[test]
+fn borrow_from_value_wrong_type() {
+    let value = Value::i4(42);
+    let error = <&str>::try_borrow_from_value(&value).unwrap_err();
+    assert_eq!(error, DxrError::wrong_type("i4", "string"));
+
+    let error = <&[u8]>::try_borrow_from_value(&value).unwrap_err();
+    assert_eq!(error, DxrError::wrong_type("i4", "base64"));
+}
+
+#[test]
+fn borrow_from_value_missing() {
+    let value = Value::structure(Struct::new(vec![]));
+    let error = Message::try_borrow_from_value(&value).unwrap_err();
+    assert_eq!(error, DxrError::missing_field("Message", "sender"));
+}
+
+struct Unit;
+
+#[derive(Debug, TryFromValue, TryToValue)]
+#[dxr(bound = "")]
+struct Tagged<T> {
+    name: String,
+    tag: PhantomData<T>,
+}
+
+#[test]
+fn phantom_data() {
+    let tagged: Tagged<Unit> = Tagged {
+        name: String::from("tagged"),
+        tag: PhantomData,
+    };
+    let value = tagged.try_to_value().unwrap();
+    let expected = Value::structure(Struct::new(vec![Member::new(
+        String::from("name"),
+        Value::string(String::from("tagged")),
+    )]));
+    assert_eq!(value, expected);
+
+    let tagged = Tagged::<Unit>::try_from_value(&value).unwrap();
+    assert_eq!(tagged.name, "tagged");
+}
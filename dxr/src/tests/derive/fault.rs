@@ -0,0 +1,170 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{DxrError, Fault, IntoFault, TryFromFault, TryFromValue, TryToValue, Value};
+
+#[derive(Debug, IntoFault, PartialEq, TryFromFault)]
+enum ApiError {
+    #[fault(code = 1001, message = "Build not found: {id}")]
+    NotFound {
+        #[fault(member)]
+        id: i32,
+    },
+    #[fault(code = 1002, message = "Permission denied for user {0}")]
+    Forbidden(String),
+    #[fault(code = Fault::INTERNAL_ERROR, message = "Internal error")]
+    Internal,
+    #[fault(code = 1003)]
+    Invalid {
+        #[fault(string)]
+        reason: String,
+    },
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Invalid { reason } => write!(f, "{reason}"),
+            _ => write!(f, "{self:?}"),
+        }
+    }
+}
+
+#[test]
+fn into_fault_named() {
+    let fault = ApiError::NotFound { id: 42 }.into_fault();
+
+    assert_eq!(fault.code(), 1001);
+    assert_eq!(fault.string(), "Build not found: 42");
+    assert_eq!(fault.member::<i32>("id").unwrap(), 42);
+}
+
+#[test]
+fn into_fault_unnamed() {
+    let fault = Fault::from(ApiError::Forbidden(String::from("alice")));
+
+    assert_eq!(
+        fault,
+        Fault::new(1002, String::from("Permission denied for user alice"))
+    );
+}
+
+#[test]
+fn into_fault_unit() {
+    let fault = ApiError::Internal.into_fault();
+
+    assert_eq!(fault, Fault::new(Fault::INTERNAL_ERROR, String::from("Internal error")));
+}
+
+#[test]
+fn into_fault_display() {
+    let fault = ApiError::Invalid {
+        reason: String::from("Invalid architecture"),
+    }
+    .into_fault();
+
+    assert_eq!(fault, Fault::new(1003, String::from("Invalid architecture")));
+}
+
+#[test]
+fn into_fault_question_mark() {
+    fn handler() -> Result<(), Fault> {
+        Err(ApiError::Internal)?;
+        Ok(())
+    }
+
+    assert_eq!(handler().unwrap_err().code(), Fault::INTERNAL_ERROR);
+}
+
+#[test]
+fn roundtrip() {
+    let errors = [
+        ApiError::NotFound { id: 42 },
+        ApiError::Forbidden(String::new()),
+        ApiError::Internal,
+        ApiError::Invalid {
+            reason: String::from("Invalid architecture"),
+        },
+    ];
+
+    for error in errors {
+        let fault = Fault::try_from_fault(&error.into_fault()).unwrap().unwrap();
+        assert_eq!(ApiError::try_from_fault(&fault).unwrap().unwrap().into_fault(), fault);
+    }
+}
+
+#[test]
+fn try_from_fault_missing_member() {
+    let fault = Fault::new(1001, String::from("Build not found"));
+
+    assert!(ApiError::try_from_fault(&fault).unwrap_err().is_missing_field());
+}
+
+#[test]
+fn try_from_fault_unknown_code() {
+    let fault = Fault::new(1004, String::from("Unknown"));
+
+    assert_eq!(ApiError::try_from_fault(&fault).unwrap(), None);
+}
+
+#[test]
+fn try_from_fault_invalid_member() {
+    let fault = Fault::new(1001, String::from("Build not found")).with_member(String::from("id"), Value::boolean(true));
+
+    assert!(ApiError::try_from_fault(&fault).unwrap_err().is_wrong_type());
+}
+
+// members that cannot be converted into values contain the conversion error instead
+#[derive(Debug, IntoFault, TryFromFault)]
+enum UploadError {
+    #[fault(code = 1010, message = "Upload failed")]
+    Failed {
+        #[fault(member)]
+        path: String,
+        #[fault(member)]
+        reason: Unencodable,
+    },
+}
+
+#[derive(Debug)]
+struct Unencodable;
+
+impl TryToValue for Unencodable {
+    fn try_to_value(&self) -> Result<Value, DxrError> {
+        Err(DxrError::invalid_data(String::from("Unencodable")))
+    }
+}
+
+impl TryFromValue for Unencodable {
+    fn try_from_value(value: &Value) -> Result<Self, DxrError> {
+        i32::try_from_value(value).map(|_| Unencodable)
+    }
+}
+
+#[test]
+fn into_fault_unencodable_member() {
+    let fault = UploadError::Failed {
+        path: String::from("/tmp/file"),
+        reason: Unencodable,
+    }
+    .into_fault();
+
+    assert_eq!(fault.code(), 1010);
+    assert_eq!(fault.member::<String>("path").unwrap(), "/tmp/file");
+    assert_eq!(
+        fault.member::<String>("reason").unwrap(),
+        "Failed to parse XML data: Unencodable"
+    );
+}
+
+#[test]
+fn try_from_fault_unencodable_member() {
+    let fault = UploadError::Failed {
+        path: String::from("/tmp/file"),
+        reason: Unencodable,
+    }
+    .into_fault();
+
+    // the member contains the conversion error, which does not have the expected type
+    let error = UploadError::try_from_fault(&fault).unwrap_err();
+    assert_eq!(error, DxrError::wrong_type("string", "i4"));
+}
@@ -0,0 +1,105 @@
+use std::marker::PhantomData;
+
+use crate::values::{Member, Struct};
+use crate::{Defaulted, DxrError, TryFromValue, TryToValue, Value, XmlRpcParams, XmlRpcType};
+
+#[derive(Debug, PartialEq, TryFromValue, TryToValue, XmlRpcType)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn point(members: Vec<(&str, Value)>) -> Value {
+    Value::structure(Struct::new(
+        members
+            .into_iter()
+            .map(|(name, value)| Member::new(String::from(name), value))
+            .collect(),
+    ))
+}
+
+#[test]
+fn from_value_unknown_member() {
+    let value = point(vec![("x", Value::i4(1)), ("y", Value::i4(2)), ("z", Value::i4(3))]);
+    assert_eq!(Point::try_from_value(&value).unwrap(), Point { x: 1, y: 2 });
+}
+
+#[test]
+fn from_value_duplicate_member() {
+    let value = point(vec![("x", Value::i4(1)), ("y", Value::i4(2)), ("x", Value::i4(3))]);
+    let error = Point::try_from_value(&value).unwrap_err();
+    assert!(error.is_duplicate_field());
+    assert_eq!(error.as_duplicate_field(), Some(("Point", "x")));
+}
+
+#[test]
+fn from_value_missing_member() {
+    let value = point(vec![("y", Value::i4(2))]);
+    let error = Point::try_from_value(&value).unwrap_err();
+    assert_eq!(error, DxrError::missing_field("Point", "x"));
+}
+
+#[test]
+fn from_value_not_a_struct() {
+    let error = Point::try_from_value(&Value::i4(1)).unwrap_err();
+    assert_eq!(error, DxrError::wrong_type("i4", "struct"));
+}
+
+#[derive(Debug, PartialEq, TryFromValue)]
+struct Settings {
+    name: String,
+    limit: Option<i32>,
+    retries: Defaulted<i32>,
+}
+
+#[test]
+fn from_value_missing_optional() {
+    let value = point(vec![("name", Value::string(String::from("foo")))]);
+    let expected = Settings {
+        name: String::from("foo"),
+        limit: None,
+        retries: Defaulted(0),
+    };
+    assert_eq!(Settings::try_from_value(&value).unwrap(), expected);
+
+    let value = point(vec![("limit", Value::i4(1))]);
+    let error = Settings::try_from_value(&value).unwrap_err();
+    assert_eq!(error, DxrError::missing_field("Settings", "name"));
+}
+
+#[derive(Debug, PartialEq, TryFromValue)]
+#[dxr(bound = "")]
+struct Empty<T> {
+    marker: PhantomData<T>,
+}
+
+#[test]
+fn from_value_empty() {
+    let value = point(vec![("x", Value::i4(1))]);
+    assert_eq!(
+        Empty::<()>::try_from_value(&value).unwrap(),
+        Empty { marker: PhantomData }
+    );
+
+    let error = Empty::<()>::try_from_value(&Value::i4(1)).unwrap_err();
+    assert_eq!(error, DxrError::wrong_type("i4", "struct"));
+}
+
+#[test]
+fn struct_members() {
+    let value = Point { x: 1, y: 2 }.try_to_value().unwrap();
+    let mut members: Vec<(&str, &Value)> = value.struct_members().unwrap().collect();
+    members.sort_by_key(|(name, _)| *name);
+    assert_eq!(members, vec![("x", &Value::i4(1)), ("y", &Value::i4(2))]);
+}
+
+#[test]
+fn xmlrpc_type() {
+    assert_eq!(Point::xmlrpc_type(), "struct");
+    assert_eq!(<Vec<Point>>::xmlrpc_type(), "array");
+    assert_eq!(<(Point, i32)>::xmlrpc_params(), vec!["struct", "i4"]);
+
+    let value = Point { x: 1, y: 2 }.try_to_value().unwrap();
+    assert!(Point::accepts(&value));
+    assert!(!Point::accepts(&Value::i4(1)));
+}
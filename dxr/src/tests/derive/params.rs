@@ -0,0 +1,278 @@
+use crate::{DxrError, TryFromParams, TryToParams, Value, XmlRpcParams};
+
+#[derive(Debug, PartialEq, TryFromParams, TryToParams, XmlRpcParams)]
+struct Required {
+    name: String,
+    count: i32,
+}
+
+#[derive(Debug, PartialEq, TryFromParams, TryToParams, XmlRpcParams)]
+struct Optional {
+    name: String,
+    #[dxr(default)]
+    count: i32,
+    #[dxr(default)]
+    flag: bool,
+}
+
+#[derive(Debug, PartialEq, TryFromParams, TryToParams, XmlRpcParams)]
+struct Variadic {
+    name: String,
+    #[dxr(variadic)]
+    rest: Vec<i32>,
+}
+
+#[test]
+fn from_required() {
+    let values = vec![Value::string(String::from("foo")), Value::i4(42)];
+    let expected = Required {
+        name: String::from("foo"),
+        count: 42,
+    };
+
+    assert_eq!(Required::try_from_params(&values).unwrap(), expected);
+}
+
+#[test]
+fn to_required() {
+    let value = Required {
+        name: String::from("foo"),
+        count: 42,
+    };
+    let expected = vec![Value::string(String::from("foo")), Value::i4(42)];
+
+    assert_eq!(value.try_to_params().unwrap(), expected);
+}
+
+#[test]
+fn from_required_missing() {
+    let values = vec![Value::string(String::from("foo"))];
+    let error = Required::try_from_params(&values).unwrap_err();
+
+    assert_eq!(
+        error.as_invalid_parameter(),
+        Some(("count", &DxrError::parameter_mismatch(1, 2)))
+    );
+}
+
+#[test]
+fn from_required_too_many() {
+    let values = vec![Value::string(String::from("foo")), Value::i4(42), Value::i4(24)];
+    let error = Required::try_from_params(&values).unwrap_err();
+
+    assert_eq!(error, DxrError::parameter_mismatch(3, 2));
+}
+
+#[test]
+fn from_required_wrong_type() {
+    let values = vec![Value::i4(42), Value::i4(42)];
+    let error = Required::try_from_params(&values).unwrap_err();
+
+    assert_eq!(
+        error.as_invalid_parameter(),
+        Some(("name", &DxrError::wrong_type("i4", "string")))
+    );
+}
+
+#[test]
+fn from_optional() {
+    let values = vec![Value::string(String::from("foo"))];
+    let expected = Optional {
+        name: String::from("foo"),
+        count: 0,
+        flag: false,
+    };
+
+    assert_eq!(Optional::try_from_params(&values).unwrap(), expected);
+
+    let values = vec![Value::string(String::from("foo")), Value::i4(42)];
+    let expected = Optional {
+        name: String::from("foo"),
+        count: 42,
+        flag: false,
+    };
+
+    assert_eq!(Optional::try_from_params(&values).unwrap(), expected);
+}
+
+#[test]
+fn from_variadic() {
+    let values = vec![Value::string(String::from("foo"))];
+    let expected = Variadic {
+        name: String::from("foo"),
+        rest: vec![],
+    };
+
+    assert_eq!(Variadic::try_from_params(&values).unwrap(), expected);
+
+    let values = vec![
+        Value::string(String::from("foo")),
+        Value::i4(1),
+        Value::i4(2),
+        Value::i4(3),
+    ];
+    let expected = Variadic {
+        name: String::from("foo"),
+        rest: vec![1, 2, 3],
+    };
+
+    assert_eq!(Variadic::try_from_params(&values).unwrap(), expected);
+}
+
+#[test]
+fn to_variadic() {
+    let value = Variadic {
+        name: String::from("foo"),
+        rest: vec![1, 2],
+    };
+    let expected = vec![Value::string(String::from("foo")), Value::i4(1), Value::i4(2)];
+
+    assert_eq!(value.try_to_params().unwrap(), expected);
+}
+
+#[derive(Debug, PartialEq, TryFromParams, TryToParams, XmlRpcParams)]
+struct Trailing {
+    name: String,
+    first: Option<i32>,
+    second: Option<String>,
+}
+
+#[test]
+fn to_trailing() {
+    let value = Trailing {
+        name: String::from("foo"),
+        first: Some(1),
+        second: None,
+    };
+    let expected = vec![Value::string(String::from("foo")), Value::i4(1)];
+    assert_eq!(value.try_to_params().unwrap(), expected);
+
+    let value = Trailing {
+        name: String::from("foo"),
+        first: None,
+        second: None,
+    };
+    let expected = vec![Value::string(String::from("foo"))];
+    assert_eq!(value.try_to_params().unwrap(), expected);
+}
+
+#[test]
+fn to_trailing_interior_none() {
+    let value = Trailing {
+        name: String::from("foo"),
+        first: None,
+        second: Some(String::from("bar")),
+    };
+
+    // omitted parameters that are followed by other parameters can only be encoded as nil values
+    #[cfg(feature = "nil")]
+    assert_eq!(
+        value.try_to_params().unwrap(),
+        vec![
+            Value::string(String::from("foo")),
+            Value::nil(),
+            Value::string(String::from("bar"))
+        ]
+    );
+    #[cfg(not(feature = "nil"))]
+    assert!(value.try_to_params().unwrap_err().is_invalid_parameter());
+}
+
+#[test]
+fn xmlrpc_params() {
+    assert_eq!(Required::xmlrpc_params(), vec!["string", "i4"]);
+    assert_eq!(Optional::xmlrpc_params(), vec!["string", "i4", "boolean"]);
+    assert_eq!(Trailing::xmlrpc_params(), vec!["string", "i4", "string"]);
+
+    // variadic parameters are not part of the signature
+    assert_eq!(Variadic::xmlrpc_params(), vec!["string"]);
+}
+
+#[test]
+fn check_params() {
+    let values = vec![Value::string(String::from("foo")), Value::i4(42)];
+
+    assert!(Required::check_params(&values).is_ok());
+    assert!(Optional::check_params(&values).is_ok());
+    assert!(Trailing::check_params(&values).is_ok());
+    assert!(Variadic::check_params(&values).is_ok());
+
+    // missing parameters are only detected when converting them
+    assert!(Required::check_params(&values[..1]).is_ok());
+
+    let error = Required::check_params(&[Value::string(String::from("foo")), Value::boolean(true)]).unwrap_err();
+    let (name, error) = error.as_invalid_parameter().unwrap();
+    assert_eq!(name, "count");
+    assert_eq!(error, &DxrError::wrong_type("boolean", "i4"));
+
+    let mut too_many = values.clone();
+    too_many.push(Value::i4(1));
+    assert_eq!(
+        Required::check_params(&too_many).unwrap_err(),
+        DxrError::parameter_mismatch(3, 2)
+    );
+
+    // variadic parameters are checked with the implementation for the type of the field
+    too_many.push(Value::boolean(false));
+    assert!(Variadic::check_params(&too_many[..3]).is_ok());
+    let error = Variadic::check_params(&too_many).unwrap_err();
+    let (name, error) = error.as_invalid_parameter().unwrap();
+    assert_eq!(name, "rest");
+    let (name, error) = error.as_invalid_parameter().unwrap();
+    assert_eq!(name, "3");
+    assert_eq!(error, &DxrError::wrong_type("boolean", "i4"));
+}
+
+#[cfg(feature = "nil")]
+mod nil {
+    use crate::{TryFromParams, TryToParams, Value};
+
+    #[derive(Debug, PartialEq, TryFromParams, TryToParams)]
+    struct Options {
+        name: String,
+        first: Option<i32>,
+        second: Option<i32>,
+    }
+
+    #[test]
+    fn from_options() {
+        let values = vec![Value::string(String::from("foo"))];
+        let expected = Options {
+            name: String::from("foo"),
+            first: None,
+            second: None,
+        };
+
+        assert_eq!(Options::try_from_params(&values).unwrap(), expected);
+
+        let values = vec![Value::string(String::from("foo")), Value::nil(), Value::i4(2)];
+        let expected = Options {
+            name: String::from("foo"),
+            first: None,
+            second: Some(2),
+        };
+
+        assert_eq!(Options::try_from_params(&values).unwrap(), expected);
+    }
+
+    #[test]
+    fn to_options() {
+        let value = Options {
+            name: String::from("foo"),
+            first: Some(1),
+            second: None,
+        };
+        let expected = vec![Value::string(String::from("foo")), Value::i4(1)];
+
+        assert_eq!(value.try_to_params().unwrap(), expected);
+
+        let value = Options {
+            name: String::from("foo"),
+            first: None,
+            second: Some(2),
+        };
+        let expected = vec![Value::string(String::from("foo")), Value::nil(), Value::i4(2)];
+
+        assert_eq!(value.try_to_params().unwrap(), expected);
+    }
+}
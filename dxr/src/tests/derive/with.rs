@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::{DxrError, TryFromParams, TryFromValue, TryToParams, TryToValue, Value, XmlRpcParams};
+
+// timestamps that are sent as <double> values
+mod timestamp {
+    use crate::{DxrError, TryFromValue, TryToValue, Value};
+
+    pub(super) fn to_value(timestamp: &u64) -> Result<Value, DxrError> {
+        (*timestamp as f64).try_to_value()
+    }
+
+    pub(super) fn from_value(value: &Value) -> Result<u64, DxrError> {
+        Ok(f64::try_from_value(value)? as u64)
+    }
+}
+
+// byte vectors that are sent as hex-encoded <string> values
+fn bytes_to_hex(bytes: &[u8]) -> Result<Value, DxrError> {
+    let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    Ok(Value::string(hex))
+}
+
+fn bytes_from_hex(value: &Value) -> Result<Vec<u8>, DxrError> {
+    let hex = String::try_from_value(value)?;
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| DxrError::invalid_data(hex.clone()))
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, TryFromValue, TryToValue, TryFromParams, TryToParams, XmlRpcParams)]
+struct Custom {
+    #[dxr(with = "timestamp")]
+    created: u64,
+    #[dxr(to_value = "bytes_to_hex", from_value = "bytes_from_hex")]
+    digest: Vec<u8>,
+}
+
+fn custom() -> Custom {
+    Custom {
+        created: 1_700_000_000,
+        digest: vec![0xde, 0xad, 0xbe, 0xef],
+    }
+}
+
+fn values() -> Vec<Value> {
+    vec![Value::double(1_700_000_000.0), Value::string(String::from("deadbeef"))]
+}
+
+#[test]
+fn to_value() {
+    let mut map = HashMap::new();
+    map.insert("created", Value::double(1_700_000_000.0));
+    map.insert("digest", Value::string(String::from("deadbeef")));
+    let expected = map.try_to_value().unwrap();
+
+    assert_eq!(custom().try_to_value().unwrap(), expected);
+}
+
+#[test]
+fn roundtrip_value() {
+    let value = custom().try_to_value().unwrap();
+    assert_eq!(Custom::try_from_value(&value).unwrap(), custom());
+}
+
+#[test]
+fn to_params() {
+    assert_eq!(custom().try_to_params().unwrap(), values());
+}
+
+#[test]
+fn from_params() {
+    assert_eq!(Custom::try_from_params(&values()).unwrap(), custom());
+}
+
+#[test]
+fn from_params_invalid() {
+    let values = vec![Value::double(0.0), Value::string(String::from("xyz"))];
+    let error = Custom::try_from_params(&values).unwrap_err();
+
+    assert_eq!(
+        error.as_invalid_parameter(),
+        Some(("digest", &DxrError::invalid_data(String::from("xyz"))))
+    );
+}
+
+#[test]
+fn xmlrpc_params() {
+    // parameters with custom conversions can have any type
+    assert_eq!(Custom::xmlrpc_params(), vec!["any", "any"]);
+    assert!(Custom::check_params(&values()).is_ok());
+    assert!(Custom::check_params(&[Value::boolean(true)]).is_ok());
+}
@@ -0,0 +1,65 @@
+use crate::{value, DateTime, MethodCall, MethodResponse, Value};
+
+#[test]
+fn display_scalars() {
+    assert_eq!(Value::i4(-42).to_string(), "-42");
+    assert_eq!(Value::boolean(true).to_string(), "True");
+    assert_eq!(Value::boolean(false).to_string(), "False");
+    assert_eq!(Value::double(1.0).to_string(), "1.0");
+    assert_eq!(Value::double(-2.5).to_string(), "-2.5");
+}
+
+#[cfg(feature = "i8")]
+#[test]
+fn display_i8() {
+    assert_eq!(Value::i8(1 << 40).to_string(), "1099511627776");
+}
+
+#[cfg(feature = "nil")]
+#[test]
+fn display_nil() {
+    assert_eq!(Value::nil().to_string(), "None");
+}
+
+#[test]
+fn display_string() {
+    assert_eq!(Value::string(String::from("HELLO")).to_string(), "'HELLO'");
+    assert_eq!(Value::string(String::from("it's")).to_string(), r"'it\'s'");
+    assert_eq!(Value::string(String::from(r#"a "b""#)).to_string(), r#"'a "b"'"#);
+    assert_eq!(Value::string(String::from("a\nb\\")).to_string(), r"'a\nb\\'");
+}
+
+#[test]
+fn display_datetime() {
+    let dt: DateTime = "20240101T12:30:00".parse().unwrap();
+    assert_eq!(Value::datetime(dt).to_string(), "<DateTime '20240101T12:30:00'>");
+}
+
+#[test]
+fn display_base64() {
+    assert_eq!(Value::base64(b"ab'\x00\xff".to_vec()).to_string(), r"b'ab\'\x00\xff'");
+}
+
+#[test]
+fn display_compound() {
+    let value = value!({"ids": [1, 2, 3], "name": "foo", "nested": {"empty": []}}).unwrap();
+    assert_eq!(
+        value.to_string(),
+        "{'ids': [1, 2, 3], 'name': 'foo', 'nested': {'empty': []}}"
+    );
+}
+
+#[test]
+fn display_call() {
+    let call = MethodCall::new("add", vec![Value::i4(1), Value::i4(2)]);
+    assert_eq!(call.to_string(), "add(1, 2)");
+
+    let call = MethodCall::new("ping", vec![]);
+    assert_eq!(call.to_string(), "ping()");
+}
+
+#[test]
+fn display_response() {
+    let response = MethodResponse::new(value!(["foo", true]).unwrap());
+    assert_eq!(response.to_string(), "['foo', True]");
+}
@@ -1,2 +1,3 @@
 mod params;
+mod types;
 mod values;
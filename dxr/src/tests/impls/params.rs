@@ -1,4 +1,4 @@
-use crate::{TryFromParams, TryToParams, Value};
+use crate::{Defaulted, Rest, TryFromParams, TryToParams, Value};
 
 #[test]
 fn to_value() {
@@ -553,3 +553,160 @@ fn from_tuple_8() {
 
     assert_eq!(value.try_to_params().unwrap(), expected);
 }
+
+// tuples with more than twelve members do not implement Debug or PartialEq
+
+type Tuple16 = (
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+);
+
+#[test]
+fn to_tuple_16() {
+    let value: Vec<Value> = (0..16).map(Value::i4).collect();
+    let (a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p) = Tuple16::try_from_params(&value).unwrap();
+
+    assert_eq!(
+        [a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p],
+        std::array::from_fn(|i| i as i32)
+    );
+}
+
+#[test]
+fn from_tuple_16() {
+    let value: Tuple16 = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let expected: Vec<Value> = (0..16).map(Value::i4).collect();
+
+    assert_eq!(value.try_to_params().unwrap(), expected);
+}
+
+#[test]
+fn to_tuple_16_fail() {
+    let value: Vec<Value> = (0..15).map(Value::i4).collect();
+
+    match Tuple16::try_from_params(&value) {
+        Ok(_) => panic!("expected parameter mismatch"),
+        Err(error) => assert_eq!(error.as_parameter_mismatch(), Some((15, 16))),
+    }
+}
+
+#[test]
+fn to_rest() {
+    let value = vec![Value::i4(1), Value::i4(2)];
+    let expected = Rest(vec![1, 2]);
+
+    assert_eq!(Rest::<i32>::try_from_params(&value).unwrap(), expected);
+}
+
+#[test]
+fn to_tuple_rest() {
+    let value = vec![Value::string(String::from("HELLO")), Value::i4(1), Value::boolean(true)];
+    let expected = (String::from("HELLO"), Rest(vec![Value::i4(1), Value::boolean(true)]));
+
+    assert_eq!(<(String, Rest<Value>)>::try_from_params(&value).unwrap(), expected);
+}
+
+#[test]
+fn to_tuple_rest_empty() {
+    let value = vec![Value::string(String::from("HELLO")), Value::i4(1)];
+    let expected = (String::from("HELLO"), 1, Rest(vec![]));
+
+    assert_eq!(<(String, i32, Rest<bool>)>::try_from_params(&value).unwrap(), expected);
+}
+
+#[test]
+fn to_tuple_rest_fail() {
+    let value = vec![Value::string(String::from("HELLO"))];
+    let error = <(String, i32, Rest<bool>)>::try_from_params(&value).unwrap_err();
+
+    assert_eq!(error.as_parameter_mismatch(), Some((1, 2)));
+
+    let value = vec![Value::string(String::from("HELLO")), Value::i4(1), Value::i4(2)];
+    let error = <(String, i32, Rest<bool>)>::try_from_params(&value).unwrap_err();
+
+    assert!(error.is_wrong_type());
+}
+
+#[test]
+fn from_tuple_rest() {
+    let value = (String::from("HELLO"), Rest(vec![1, 2]));
+    let expected = vec![Value::string(String::from("HELLO")), Value::i4(1), Value::i4(2)];
+
+    assert_eq!(value.try_to_params().unwrap(), expected);
+}
+
+#[test]
+fn to_tuple_optional() {
+    let value = vec![Value::string(String::from("HELLO")), Value::i4(1)];
+    let expected = (String::from("HELLO"), Some(1), None);
+
+    assert_eq!(
+        <(String, Option<i32>, Option<bool>)>::try_from_params(&value).unwrap(),
+        expected
+    );
+
+    let value = vec![Value::string(String::from("HELLO"))];
+    let expected = (String::from("HELLO"), None, None);
+
+    assert_eq!(
+        <(String, Option<i32>, Option<bool>)>::try_from_params(&value).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn to_tuple_optional_fail() {
+    let value = vec![];
+    let error = <(String, Option<i32>)>::try_from_params(&value).unwrap_err();
+
+    assert_eq!(error.as_parameter_mismatch(), Some((0, 2)));
+
+    let value = vec![Value::string(String::from("HELLO")), Value::i4(1), Value::i4(2)];
+    let error = <(String, Option<i32>)>::try_from_params(&value).unwrap_err();
+
+    assert_eq!(error.as_parameter_mismatch(), Some((3, 2)));
+}
+
+#[test]
+fn to_tuple_defaulted() {
+    let value = vec![Value::string(String::from("HELLO"))];
+    let expected = (String::from("HELLO"), Defaulted(0), Defaulted(String::new()));
+
+    assert_eq!(
+        <(String, Defaulted<i32>, Defaulted<String>)>::try_from_params(&value).unwrap(),
+        expected
+    );
+
+    let value = vec![Value::string(String::from("HELLO")), Value::i4(42)];
+    let expected = (String::from("HELLO"), Defaulted(42), Defaulted(String::new()));
+
+    assert_eq!(
+        <(String, Defaulted<i32>, Defaulted<String>)>::try_from_params(&value).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn to_tuple_optional_rest() {
+    let value = vec![Value::string(String::from("HELLO"))];
+    let expected = (String::from("HELLO"), None, Rest(vec![]));
+
+    assert_eq!(
+        <(String, Option<i32>, Rest<bool>)>::try_from_params(&value).unwrap(),
+        expected
+    );
+}
@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{DateTime, Defaulted, DxrError, Rest, Value, XmlRpcParams, XmlRpcType};
+
+#[test]
+fn primitive_types() {
+    assert_eq!(i32::xmlrpc_type(), "i4");
+    #[cfg(feature = "i8")]
+    assert_eq!(i64::xmlrpc_type(), "i8");
+    assert_eq!(bool::xmlrpc_type(), "boolean");
+    assert_eq!(String::xmlrpc_type(), "string");
+    assert_eq!(<&str>::xmlrpc_type(), "string");
+    assert_eq!(<Cow<'_, str>>::xmlrpc_type(), "string");
+    assert_eq!(f64::xmlrpc_type(), "double");
+    assert_eq!(DateTime::xmlrpc_type(), "dateTime.iso8601");
+    assert_eq!(<Vec<u8>>::xmlrpc_type(), "base64");
+    assert_eq!(<&[u8]>::xmlrpc_type(), "base64");
+    assert_eq!(Value::xmlrpc_type(), "any");
+}
+
+#[test]
+fn compound_types() {
+    assert_eq!(<Vec<i32>>::xmlrpc_type(), "array");
+    assert_eq!(<[String; 2]>::xmlrpc_type(), "array");
+    assert_eq!(<(i32, String)>::xmlrpc_type(), "array");
+    assert_eq!(<HashMap<String, f64>>::xmlrpc_type(), "struct");
+    assert_eq!(<Option<bool>>::xmlrpc_type(), "boolean");
+    assert_eq!(<Defaulted<i32>>::xmlrpc_type(), "i4");
+    assert_eq!(<Arc<Vec<String>>>::xmlrpc_type(), "array");
+}
+
+#[test]
+fn accepts() {
+    assert!(i32::accepts(&Value::i4(1)));
+    assert!(!i32::accepts(&Value::string(String::from("1"))));
+    assert!(Value::accepts(&Value::boolean(true)));
+    assert!(<Option<i32>>::accepts(&Value::i4(1)));
+    #[cfg(feature = "nil")]
+    assert!(<Option<i32>>::accepts(&Value::nil()));
+}
+
+#[test]
+fn params() {
+    assert_eq!(<()>::xmlrpc_params(), Vec::<&str>::new());
+    assert_eq!(<(i32,)>::xmlrpc_params(), vec!["i4"]);
+    assert_eq!(
+        <(String, Vec<i32>, bool)>::xmlrpc_params(),
+        vec!["string", "array", "boolean"]
+    );
+}
+
+#[test]
+fn check_params() {
+    let values = vec![Value::i4(1), Value::string(String::from("two"))];
+
+    assert!(<(i32, String)>::check_params(&values).is_ok());
+    assert!(<(i32, String, Option<bool>)>::check_params(&values).is_ok());
+    assert_eq!(
+        <(i32,)>::check_params(&values).unwrap_err(),
+        DxrError::parameter_mismatch(2, 1)
+    );
+    assert_eq!(
+        <()>::check_params(&values).unwrap_err(),
+        DxrError::parameter_mismatch(2, 0)
+    );
+
+    let error = <(i32, i32)>::check_params(&values).unwrap_err();
+    let (name, error) = error.as_invalid_parameter().unwrap();
+    assert_eq!(name, "2");
+    assert_eq!(error, &DxrError::wrong_type("string", "i4"));
+}
+
+#[test]
+fn rest_params() {
+    assert_eq!(<Rest<i32>>::xmlrpc_params(), Vec::<&str>::new());
+    assert_eq!(<Vec<i32>>::xmlrpc_params(), Vec::<&str>::new());
+    assert_eq!(<(String, Rest<i32>)>::xmlrpc_params(), vec!["string"]);
+    assert_eq!(
+        <(String, bool, Rest<Value>)>::xmlrpc_params(),
+        vec!["string", "boolean"]
+    );
+}
+
+#[test]
+fn check_rest_params() {
+    let values = vec![Value::string(String::from("one")), Value::i4(2), Value::i4(3)];
+
+    assert!(<(String, Rest<i32>)>::check_params(&values).is_ok());
+    assert!(<(String, i32, i32, Rest<i32>)>::check_params(&values).is_ok());
+    assert!(<(String, i32, i32, Option<i32>, Rest<i32>)>::check_params(&values).is_ok());
+    assert!(<Rest<Value>>::check_params(&values).is_ok());
+    assert!(<Rest<i32>>::check_params(&values[1..]).is_ok());
+    assert!(<Vec<i32>>::check_params(&[]).is_ok());
+
+    let error = <Rest<i32>>::check_params(&values).unwrap_err();
+    let (name, error) = error.as_invalid_parameter().unwrap();
+    assert_eq!(name, "1");
+    assert_eq!(error, &DxrError::wrong_type("string", "i4"));
+
+    // trailing parameters are numbered by their position in the complete parameter list
+    let error = <(String, i32, Rest<String>)>::check_params(&values).unwrap_err();
+    let (name, error) = error.as_invalid_parameter().unwrap();
+    assert_eq!(name, "3");
+    assert_eq!(error, &DxrError::wrong_type("i4", "string"));
+
+    let error = <(i32, Rest<i32>)>::check_params(&values).unwrap_err();
+    let (name, error) = error.as_invalid_parameter().unwrap();
+    assert_eq!(name, "1");
+    assert_eq!(error, &DxrError::wrong_type("string", "i4"));
+}
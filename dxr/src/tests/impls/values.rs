@@ -401,6 +401,18 @@ fn from_unit() {
     <()>::try_from_value(&value).unwrap();
 }
 
+#[test]
+fn from_option() {
+    assert_eq!(<Option<i32>>::try_from_value(&Value::i4(1)).unwrap(), Some(1));
+}
+
+#[cfg(feature = "nil")]
+#[test]
+fn to_option() {
+    assert_eq!(Some(1).try_to_value().unwrap(), Value::i4(1));
+    assert_eq!(None::<i32>.try_to_value().unwrap(), Value::nil());
+}
+
 #[cfg(feature = "nil")]
 #[test]
 fn from_unit_nil() {
@@ -732,3 +744,52 @@ fn from_tuple_8_fail() {
             .is_wrong_type()
     );
 }
+
+// tuples with more than twelve members do not implement Debug or PartialEq
+
+type Tuple16 = (
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+);
+
+#[test]
+fn to_tuple_16() {
+    let value: Tuple16 = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let expected = (0..16).map(Value::i4).collect::<Vec<Value>>().try_to_value().unwrap();
+
+    assert_eq!(value.try_to_value().unwrap(), expected);
+}
+
+#[test]
+fn from_tuple_16() {
+    let value = (0..16).map(Value::i4).collect::<Vec<Value>>().try_to_value().unwrap();
+    let (a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p) = Tuple16::try_from_value(&value).unwrap();
+
+    assert_eq!(
+        [a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p],
+        std::array::from_fn(|i| i as i32)
+    );
+}
+
+#[test]
+fn from_tuple_optional_fail() {
+    // optional tuple members are only supported for method call parameters, not for arrays
+    let value = vec![Value::i4(1)].try_to_value().unwrap();
+    assert!(<(i32, Option<i32>)>::try_from_value(&value)
+        .unwrap_err()
+        .is_parameter_mismatch());
+}
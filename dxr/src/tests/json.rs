@@ -0,0 +1,128 @@
+use serde_json::json;
+
+use crate::{
+    value, DateTime, JsonBase64, JsonDateTime, JsonIntegerOverflow, JsonNull, JsonNumbers, JsonOptions, Value,
+};
+
+#[test]
+fn to_json() {
+    let value = value!({"name": "foo", "ids": [1, 2, 3], "ratio": 0.5, "enabled": true}).unwrap();
+    let expected = json!({"name": "foo", "ids": [1, 2, 3], "ratio": 0.5, "enabled": true});
+
+    assert_eq!(serde_json::Value::try_from(&value).unwrap(), expected);
+}
+
+#[test]
+fn from_json() {
+    let json = json!({"name": "foo", "ids": [1, 2, 3], "ratio": 0.5, "enabled": true});
+    let expected = value!({"name": "foo", "ids": [1, 2, 3], "ratio": 0.5, "enabled": true}).unwrap();
+
+    assert_eq!(Value::try_from(&json).unwrap(), expected);
+}
+
+#[test]
+fn numbers() {
+    assert_eq!(Value::try_from(json!(1)).unwrap(), Value::i4(1));
+    assert_eq!(Value::try_from(json!(1.0)).unwrap(), Value::double(1.0));
+    assert_eq!(serde_json::Value::try_from(Value::double(1.0)).unwrap(), json!(1.0));
+    assert_eq!(serde_json::Value::try_from(Value::i4(1)).unwrap(), json!(1));
+}
+
+#[test]
+fn numbers_double() {
+    let options = JsonOptions::new().numbers(JsonNumbers::Double);
+
+    assert_eq!(options.json_to_value(&json!(1)).unwrap(), Value::double(1.0));
+    assert_eq!(options.json_to_value(&json!(1.5)).unwrap(), Value::double(1.5));
+    assert_eq!(options.value_to_json(&Value::i4(1)).unwrap(), json!(1.0));
+}
+
+#[test]
+fn non_finite_double() {
+    let error = serde_json::Value::try_from(Value::double(f64::NAN)).unwrap_err();
+    assert!(error.is_invalid_data());
+}
+
+#[cfg(not(feature = "i8"))]
+#[test]
+fn integer_overflow() {
+    let json = json!(1_i64 << 40);
+
+    assert!(Value::try_from(&json).unwrap_err().is_invalid_data());
+
+    let options = JsonOptions::new().integer_overflow(JsonIntegerOverflow::Double);
+    assert_eq!(
+        options.json_to_value(&json).unwrap(),
+        Value::double((1_i64 << 40) as f64)
+    );
+}
+
+#[cfg(feature = "i8")]
+#[test]
+fn integer_overflow() {
+    let json = json!(1_i64 << 40);
+    assert_eq!(Value::try_from(&json).unwrap(), Value::i8(1 << 40));
+
+    let json = json!(u64::MAX);
+    assert!(Value::try_from(&json).unwrap_err().is_invalid_data());
+
+    let options = JsonOptions::new().integer_overflow(JsonIntegerOverflow::Double);
+    assert_eq!(options.json_to_value(&json).unwrap(), Value::double(u64::MAX as f64));
+}
+
+#[test]
+fn datetime() {
+    let dt: DateTime = "20240101T12:30:00".parse().unwrap();
+    assert_eq!(
+        serde_json::Value::try_from(Value::datetime(dt)).unwrap(),
+        json!("20240101T12:30:00")
+    );
+}
+
+#[test]
+fn datetime_iso8601() {
+    let dt: DateTime = "20240101T12:30:00".parse().unwrap();
+    let options = JsonOptions::new().datetime(JsonDateTime::Iso8601);
+
+    assert_eq!(
+        options.value_to_json(&Value::datetime(dt)).unwrap(),
+        json!("2024-01-01T12:30:00")
+    );
+}
+
+#[test]
+fn base64() {
+    let value = Value::base64(b"hello".to_vec());
+    assert_eq!(serde_json::Value::try_from(&value).unwrap(), json!("aGVsbG8="));
+
+    let options = JsonOptions::new().base64(JsonBase64::Array);
+    assert_eq!(options.value_to_json(&value).unwrap(), json!([104, 101, 108, 108, 111]));
+}
+
+#[cfg(feature = "nil")]
+#[test]
+fn null() {
+    assert_eq!(Value::try_from(json!(null)).unwrap(), Value::nil());
+    assert_eq!(serde_json::Value::try_from(Value::nil()).unwrap(), json!(null));
+}
+
+#[cfg(not(feature = "nil"))]
+#[test]
+fn null() {
+    assert!(Value::try_from(json!(null)).unwrap_err().is_wrong_type());
+}
+
+#[test]
+fn null_omit() {
+    let options = JsonOptions::new().null(JsonNull::Omit);
+
+    let json = json!({"name": "foo", "missing": null});
+    assert_eq!(options.json_to_value(&json).unwrap(), value!({"name": "foo"}).unwrap());
+    assert!(options.json_to_value(&json!([null])).unwrap_err().is_wrong_type());
+}
+
+#[test]
+fn null_error() {
+    let options = JsonOptions::new().null(JsonNull::Error);
+    assert!(options.json_to_value(&json!(null)).unwrap_err().is_wrong_type());
+}
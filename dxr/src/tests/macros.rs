@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use crate::{params, value, DateTime, DxrError, TryToValue, Value};
+
+#[test]
+fn value_primitives() {
+    assert_eq!(value!(42).unwrap(), Value::i4(42));
+    assert_eq!(value!(-1).unwrap(), Value::i4(-1));
+    assert_eq!(value!(true).unwrap(), Value::boolean(true));
+    assert_eq!(value!(1.5).unwrap(), Value::double(1.5));
+    assert_eq!(value!("HELLO").unwrap(), Value::string(String::from("HELLO")));
+}
+
+#[test]
+fn value_expressions() {
+    let name = String::from("HELLO");
+    let bytes = vec![1u8, 2, 3];
+    let dt: DateTime = "20240101T12:00:00".parse().unwrap();
+
+    assert_eq!(value!(name).unwrap(), Value::string(String::from("HELLO")));
+    assert_eq!(value!(bytes).unwrap(), Value::base64(vec![1, 2, 3]));
+    assert_eq!(value!(dt).unwrap(), Value::datetime(dt));
+    assert_eq!(value!(1 + 2).unwrap(), Value::i4(3));
+    assert_eq!(value!(name.len() as i32).unwrap(), Value::i4(5));
+}
+
+#[test]
+fn value_array() {
+    let expected = vec![1, 2, 3].try_to_value().unwrap();
+
+    assert_eq!(value!([1, 2, 3]).unwrap(), expected);
+    assert_eq!(value!([1, 2, 3,]).unwrap(), expected);
+    assert_eq!(value!([]).unwrap(), Vec::<i32>::new().try_to_value().unwrap());
+}
+
+#[test]
+fn value_struct() {
+    let mut map: HashMap<&str, Value> = HashMap::new();
+    map.insert("name", Value::string(String::from("foo")));
+    map.insert("ids", vec![1, 2, 3].try_to_value().unwrap());
+    let expected = map.try_to_value().unwrap();
+
+    assert_eq!(value!({"name": "foo", "ids": [1, 2, 3]}).unwrap(), expected);
+    assert_eq!(value!({"name": "foo", "ids": [1, 2, 3],}).unwrap(), expected);
+    assert_eq!(
+        value!({}).unwrap(),
+        HashMap::<&str, Value>::new().try_to_value().unwrap()
+    );
+}
+
+#[test]
+fn value_nested() {
+    let ids = vec![4, 5];
+
+    let mut inner: HashMap<&str, Value> = HashMap::new();
+    inner.insert("enabled", Value::boolean(true));
+    inner.insert("ids", ids.try_to_value().unwrap());
+
+    let mut outer: HashMap<&str, Value> = HashMap::new();
+    outer.insert("inner", inner.try_to_value().unwrap());
+    outer.insert(
+        "list",
+        vec![
+            vec![1, 2].try_to_value().unwrap(),
+            inner.try_to_value().unwrap(),
+            Value::string(String::from("end")),
+        ]
+        .try_to_value()
+        .unwrap(),
+    );
+
+    let value = value!({
+        "inner": {"enabled": true, "ids": ids},
+        "list": [[1, 2], {"enabled": true, "ids": [4, 5]}, "end"],
+    })
+    .unwrap();
+
+    assert_eq!(value, outer.try_to_value().unwrap());
+}
+
+#[test]
+fn params() {
+    assert_eq!(params![].unwrap(), Vec::<Value>::new());
+    assert_eq!(
+        params![1, "two", [3]].unwrap(),
+        vec![
+            Value::i4(1),
+            Value::string(String::from("two")),
+            vec![3].try_to_value().unwrap()
+        ]
+    );
+}
+
+#[cfg(feature = "nil")]
+#[test]
+fn value_nil() {
+    assert_eq!(value!(nil).unwrap(), Value::nil());
+    assert_eq!(value!([1, nil]).unwrap(), vec![Some(1), None].try_to_value().unwrap());
+
+    let mut map: HashMap<&str, Option<i32>> = HashMap::new();
+    map.insert("missing", None);
+    assert_eq!(value!({"missing": nil}).unwrap(), map.try_to_value().unwrap());
+
+    assert_eq!(params![nil, 1].unwrap(), vec![Value::nil(), Value::i4(1)]);
+}
+
+// custom type with a conversion that always fails
+struct Invalid;
+
+impl TryToValue for Invalid {
+    fn try_to_value(&self) -> Result<Value, DxrError> {
+        Err(DxrError::invalid_data(String::from("invalid")))
+    }
+}
+
+#[test]
+fn value_conversion_error() {
+    let error = value!({"ok": 1, "nested": [true, Invalid]}).unwrap_err();
+    assert_eq!(error, DxrError::invalid_data(String::from("invalid")));
+
+    let error = params![1, Invalid].unwrap_err();
+    assert!(error.is_invalid_data());
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use crate::{value, Difference, TryToValue, Value};
+
+#[test]
+fn pointer_root() {
+    let value = value!({"id": 1}).unwrap();
+    assert_eq!(value.pointer(""), Some(&value));
+}
+
+#[test]
+fn pointer_nested() {
+    let value = value!({"builds": [{"id": 1}, {"id": 2, "owner_name": "bob"}]}).unwrap();
+
+    assert_eq!(
+        value.pointer("/builds/1/owner_name"),
+        Some(&Value::string(String::from("bob")))
+    );
+    assert_eq!(value.pointer("/builds/0/id"), Some(&Value::i4(1)));
+    assert_eq!(value.pointer("/builds/0/owner_name"), None);
+    assert_eq!(value.pointer("/builds/2"), None);
+    assert_eq!(value.pointer("/builds/01"), None);
+    assert_eq!(value.pointer("/builds/+1"), None);
+    assert_eq!(value.pointer("/builds/0/id/0"), None);
+    assert_eq!(value.pointer("builds"), None);
+}
+
+#[test]
+fn pointer_escaped() {
+    let value = value!({"a/b": 1, "c~d": 2, "": 3}).unwrap();
+
+    assert_eq!(value.pointer("/a~1b"), Some(&Value::i4(1)));
+    assert_eq!(value.pointer("/c~0d"), Some(&Value::i4(2)));
+    assert_eq!(value.pointer("/"), Some(&Value::i4(3)));
+}
+
+#[test]
+fn pointer_mut() {
+    let mut value = value!({"builds": [{"id": 1}]}).unwrap();
+
+    *value.pointer_mut("/builds/0/id").unwrap() = Value::i4(2);
+    assert_eq!(value, value!({"builds": [{"id": 2}]}).unwrap());
+}
+
+#[test]
+fn diff_equal() {
+    let left = value!({"a": 1, "b": [true, "x"]}).unwrap();
+    let right = value!({"b": [true, "x"], "a": 1}).unwrap();
+    assert!(left.diff(&right).is_empty());
+}
+
+#[test]
+fn diff_members() {
+    let left = value!({"a": 1, "b": 2}).unwrap();
+    let right = value!({"b": 2, "c": 3}).unwrap();
+
+    assert_eq!(
+        left.diff(&right),
+        vec![
+            Difference::Removed {
+                path: String::from("/a"),
+                value: &Value::i4(1)
+            },
+            Difference::Added {
+                path: String::from("/c"),
+                value: &Value::i4(3)
+            },
+        ]
+    );
+}
+
+#[test]
+fn diff_items() {
+    let left = value!([1, 2, 3]).unwrap();
+    let right = value!([1, 5]).unwrap();
+
+    assert_eq!(
+        left.diff(&right),
+        vec![
+            Difference::Changed {
+                path: String::from("/1"),
+                left: &Value::i4(2),
+                right: &Value::i4(5)
+            },
+            Difference::Removed {
+                path: String::from("/2"),
+                value: &Value::i4(3)
+            },
+        ]
+    );
+}
+
+#[test]
+fn diff_types() {
+    let left = value!({"x/y": {"id": 1}}).unwrap();
+    let right = value!({"x/y": {"id": "1"}}).unwrap();
+
+    let diff = left.diff(&right);
+    assert_eq!(
+        diff,
+        vec![Difference::TypeChanged {
+            path: String::from("/x~1y/id"),
+            left: &Value::i4(1),
+            right: &Value::string(String::from("1"))
+        }]
+    );
+    assert_eq!(diff[0].to_string(), "~ /x~1y/id: 1 (i4) -> '1' (string)");
+}
+
+#[test]
+fn diff_nan() {
+    let left = value!({"x": f64::NAN}).unwrap();
+    let right = value!({"x": f64::NAN}).unwrap();
+    assert!(left.diff(&right).is_empty());
+
+    let other = value!({"x": 1.5}).unwrap();
+    assert_eq!(left.diff(&other).len(), 1);
+}
+
+#[test]
+fn diff_many_members() {
+    let mut left: HashMap<String, i32> = (0..100).map(|i| (format!("m{i}"), i)).collect();
+    let right = left.clone().try_to_value().unwrap();
+    left.insert(String::from("m42"), -1);
+    let left = left.try_to_value().unwrap();
+
+    assert_eq!(
+        left.diff(&right),
+        vec![Difference::Changed {
+            path: String::from("/m42"),
+            left: &Value::i4(-1),
+            right: &Value::i4(42)
+        }]
+    );
+}
+
+#[test]
+fn diff_display() {
+    let left = value!({"a": 1, "b": [1]}).unwrap();
+    let right = value!({"a": 2, "b": [], "c": true}).unwrap();
+
+    let lines: Vec<String> = left.diff(&right).iter().map(ToString::to_string).collect();
+    assert_eq!(lines, vec!["~ /a: 1 -> 2", "- /b/0: 1", "+ /c: True"]);
+}
@@ -0,0 +1,166 @@
+use crate::{value, walk_value, DateTime, TryFromValue, Value, Visit, VisitMut};
+
+#[derive(Default)]
+struct Paths {
+    current: Vec<String>,
+    leaves: Vec<String>,
+}
+
+impl Visit for Paths {
+    fn visit_value(&mut self, value: &Value) {
+        match value.struct_members() {
+            Ok(_) => walk_value(self, value),
+            Err(_) if Vec::<Value>::try_from_value(value).is_ok() => walk_value(self, value),
+            Err(_) => self.leaves.push(format!("/{}", self.current.join("/"))),
+        }
+    }
+
+    fn visit_member(&mut self, name: &str, value: &Value) {
+        self.current.push(name.to_owned());
+        self.visit_value(value);
+        self.current.pop();
+    }
+
+    fn visit_item(&mut self, index: usize, value: &Value) {
+        self.current.push(index.to_string());
+        self.visit_value(value);
+        self.current.pop();
+    }
+}
+
+#[test]
+fn visit_paths() {
+    let mut visitor = Paths::default();
+    visitor.visit_value(&value!({"a": [1, {"b": true}], "c": "x"}).unwrap());
+    assert_eq!(visitor.leaves, vec!["/a/0", "/a/1/b", "/c"]);
+}
+
+#[derive(Default)]
+struct Counter {
+    ints: i64,
+    booleans: usize,
+    strings: usize,
+    doubles: usize,
+    bytes: usize,
+    datetimes: usize,
+}
+
+impl Visit for Counter {
+    fn visit_i4(&mut self, int: i32) {
+        self.ints += i64::from(int);
+    }
+
+    fn visit_boolean(&mut self, _boolean: bool) {
+        self.booleans += 1;
+    }
+
+    fn visit_string(&mut self, _string: &str) {
+        self.strings += 1;
+    }
+
+    fn visit_double(&mut self, _double: f64) {
+        self.doubles += 1;
+    }
+
+    fn visit_datetime(&mut self, _datetime: &DateTime) {
+        self.datetimes += 1;
+    }
+
+    fn visit_base64(&mut self, bytes: &[u8]) {
+        self.bytes += bytes.len();
+    }
+}
+
+#[test]
+fn visit_scalars() {
+    let datetime: DateTime = "20240101T12:00:00".parse().unwrap();
+    let value = value!({
+        "ints": [1, 2, 3],
+        "nested": {"flag": true, "name": "x", "ratio": 0.5},
+        "data": (vec![0u8, 1, 2]),
+        "when": datetime
+    })
+    .unwrap();
+
+    let mut counter = Counter::default();
+    counter.visit_value(&value);
+
+    assert_eq!(counter.ints, 6);
+    assert_eq!(counter.booleans, 1);
+    assert_eq!(counter.strings, 1);
+    assert_eq!(counter.doubles, 1);
+    assert_eq!(counter.bytes, 3);
+    assert_eq!(counter.datetimes, 1);
+}
+
+struct Truncate(usize);
+
+impl VisitMut for Truncate {
+    fn visit_string_mut(&mut self, string: &mut String) {
+        if let Some((index, _)) = string.char_indices().nth(self.0) {
+            string.truncate(index);
+        }
+    }
+}
+
+#[test]
+fn visit_mut_truncate() {
+    let mut value = value!({"short": "abc", "long": ["äbcdefgh", {"deep": "abcdefgh"}]}).unwrap();
+    Truncate(4).visit_value_mut(&mut value);
+    assert_eq!(
+        value,
+        value!({"short": "abc", "long": ["äbcd", {"deep": "abcd"}]}).unwrap()
+    );
+}
+
+struct DateTimesToStrings;
+
+impl VisitMut for DateTimesToStrings {
+    fn visit_value_mut(&mut self, value: &mut Value) {
+        match DateTime::try_from_value(value) {
+            Ok(datetime) => *value = Value::string(datetime.to_string()),
+            Err(_) => crate::walk_value_mut(self, value),
+        }
+    }
+}
+
+#[test]
+fn visit_mut_replace() {
+    let datetime: DateTime = "20240101T12:00:00".parse().unwrap();
+    let mut value = value!([datetime, {"created": datetime}, 1]).unwrap();
+    DateTimesToStrings.visit_value_mut(&mut value);
+    assert_eq!(
+        value,
+        value!(["20240101T12:00:00", {"created": "20240101T12:00:00"}, 1]).unwrap()
+    );
+}
+
+struct Negate;
+
+impl VisitMut for Negate {
+    fn visit_i4_mut(&mut self, int: &mut i32) {
+        *int = -*int;
+    }
+
+    fn visit_boolean_mut(&mut self, boolean: &mut bool) {
+        *boolean = !*boolean;
+    }
+
+    fn visit_double_mut(&mut self, double: &mut f64) {
+        *double = -*double;
+    }
+
+    fn visit_item_mut(&mut self, index: usize, value: &mut Value) {
+        // skip the first item of every array
+        if index > 0 {
+            self.visit_value_mut(value);
+        }
+    }
+}
+
+#[test]
+fn visit_mut_scalars() {
+    let mut value = value!({"a": [1, 2, true], "b": 1.5}).unwrap();
+    Negate.visit_value_mut(&mut value);
+    assert_eq!(value, value!({"a": [1, -2, false], "b": -1.5}).unwrap());
+}
@@ -1,6 +1,11 @@
 mod arrays;
 mod call;
+mod encoding;
+mod interop;
+mod markup;
+mod pretty;
 mod response;
 mod structs;
 mod types;
+mod validation;
 mod values;
@@ -0,0 +1,139 @@
+use encoding_rs::{ISO_8859_2, UTF_16BE, UTF_16LE, WINDOWS_1252};
+
+use crate::{
+    decode_xml, deserialize_xml_bytes, from_reader, serialize_xml_bytes, value, DecodeError, MethodCall,
+    MethodResponse, Value, XmlOptions,
+};
+
+#[test]
+fn decode_utf8() {
+    let xml = "<value><string>Grüße</string></value>";
+    assert_eq!(decode_xml(xml.as_bytes()).unwrap(), xml);
+
+    let value: Value = deserialize_xml_bytes(xml.as_bytes()).unwrap();
+    assert_eq!(value, value!("Grüße").unwrap());
+}
+
+#[test]
+fn decode_utf8_bom() {
+    let xml = "\u{FEFF}<value><string>Grüße</string></value>";
+    let value: Value = deserialize_xml_bytes(xml.as_bytes()).unwrap();
+    assert_eq!(value, value!("Grüße").unwrap());
+}
+
+#[test]
+fn decode_invalid_utf8() {
+    let xml = b"<value><string>Gr\xFC\xDFe</string></value>";
+    assert!(deserialize_xml_bytes::<Value>(xml).is_err());
+    assert_eq!(
+        decode_xml(xml).unwrap_err(),
+        DecodeError::InvalidCharacter { encoding: "UTF-8" }
+    );
+}
+
+#[test]
+fn decode_latin1() {
+    let xml = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>\n<methodCall><methodName>gr\xFC\xDFe</methodName>\
+<params><param><value><string>caf\xE9 \x80</string></value></param></params></methodCall>";
+
+    // ISO-8859-1 is treated as Windows-1252 (in accordance with the WHATWG encoding standard)
+    let call: MethodCall = deserialize_xml_bytes(xml).unwrap();
+    assert_eq!(call.name(), "grüße");
+    assert_eq!(call.params(), vec![value!("café €").unwrap()]);
+}
+
+#[test]
+fn decode_single_quotes() {
+    let xml = b"<?xml version='1.0' encoding = 'windows-1252' ?><value><string>\xE9</string></value>";
+    let value: Value = deserialize_xml_bytes(xml).unwrap();
+    assert_eq!(value, value!("é").unwrap());
+}
+
+#[test]
+fn decode_unknown() {
+    let xml = b"<?xml version=\"1.0\" encoding=\"EBCDIC-FOO\"?><value><i4>1</i4></value>";
+    let error = deserialize_xml_bytes::<Value>(xml).unwrap_err();
+    assert_eq!(error.to_string(), "Unsupported encoding: EBCDIC-FOO");
+    assert_eq!(
+        decode_xml(xml).unwrap_err(),
+        DecodeError::UnsupportedEncoding {
+            encoding: String::from("EBCDIC-FOO")
+        }
+    );
+}
+
+#[test]
+fn decode_utf16_bom() {
+    let xml = "<?xml version=\"1.0\" encoding=\"UTF-16\"?><value><string>Grüße</string></value>";
+
+    let le: Vec<u8> = [0xFF, 0xFE]
+        .into_iter()
+        .chain(xml.encode_utf16().flat_map(u16::to_le_bytes))
+        .collect();
+    let be: Vec<u8> = [0xFE, 0xFF]
+        .into_iter()
+        .chain(xml.encode_utf16().flat_map(u16::to_be_bytes))
+        .collect();
+
+    assert_eq!(deserialize_xml_bytes::<Value>(&le).unwrap(), value!("Grüße").unwrap());
+    assert_eq!(deserialize_xml_bytes::<Value>(&be).unwrap(), value!("Grüße").unwrap());
+}
+
+#[test]
+fn decode_utf16_without_bom() {
+    // documents that declare UTF-16 without a byte order mark are ASCII-compatible
+    let xml = "<?xml version=\"1.0\" encoding=\"UTF-16\"?><value><string>Grüße</string></value>";
+    assert_eq!(
+        deserialize_xml_bytes::<Value>(xml.as_bytes()).unwrap(),
+        value!("Grüße").unwrap()
+    );
+}
+
+#[test]
+fn reader() {
+    let xml = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>\
+<methodResponse><params><param><value>caf\xE9</value></param></params></methodResponse>";
+
+    let response: MethodResponse = from_reader(&xml[..]).unwrap();
+    assert_eq!(response.inner(), value!("café").unwrap());
+}
+
+#[test]
+fn encode_default() {
+    let value = value!("Grüße").unwrap();
+
+    assert_eq!(
+        serialize_xml_bytes(&value, &XmlOptions::new()).unwrap(),
+        "<value><string>Grüße</string></value>".as_bytes()
+    );
+    assert_eq!(
+        serialize_xml_bytes(&value, &XmlOptions::new().declaration(true)).unwrap(),
+        r#"<?xml version="1.0"?><value><string>Grüße</string></value>"#.as_bytes()
+    );
+}
+
+#[test]
+fn encode_windows1252() {
+    let value = value!("café € ✓").unwrap();
+    let options = XmlOptions::new().encoding(WINDOWS_1252);
+
+    let bytes = serialize_xml_bytes(&value, &options).unwrap();
+    assert_eq!(
+        bytes,
+        b"<?xml version=\"1.0\" encoding=\"windows-1252\"?><value><string>caf\xE9 \x80 &#10003;</string></value>"
+    );
+    assert_eq!(deserialize_xml_bytes::<Value>(&bytes).unwrap(), value);
+}
+
+#[test]
+fn encode_roundtrip() {
+    let call = MethodCall::new("zażółć", vec![value!({"gęślą": "jaźń"}).unwrap(), value!(1).unwrap()]);
+
+    for encoding in [ISO_8859_2, UTF_16LE, UTF_16BE] {
+        let options = XmlOptions::new().encoding(encoding).indent(' ', 2);
+        let bytes = serialize_xml_bytes(&call, &options).unwrap();
+
+        let decoded: MethodCall = deserialize_xml_bytes(&bytes).unwrap();
+        assert_eq!(decoded, call, "{}", encoding.name());
+    }
+}
@@ -0,0 +1,244 @@
+//! documents in the formats that are produced by other XML-RPC implementations
+
+use crate::fault::Fault;
+use crate::values::{FaultResponse, MethodCall, MethodResponse, Value};
+use crate::xml::{deserialize_xml as from_str, deserialize_xml_bytes as from_bytes};
+use crate::{value, DateTime};
+
+// struct members are compared independent of their order
+#[track_caller]
+fn assert_same(left: &Value, right: &Value) {
+    let diff = left.diff(right);
+    assert!(diff.is_empty(), "{left} != {right}: {diff:?}");
+}
+
+#[track_caller]
+fn assert_same_call(left: &MethodCall, right: &MethodCall) {
+    assert_eq!(left.name(), right.name());
+    assert_eq!(left.values().count(), right.values().count());
+    for (left, right) in left.values().zip(right.values()) {
+        assert_same(left, right);
+    }
+}
+
+// Python: xmlrpc.client.dumps((41, {...}), "examples.getStateName")
+#[test]
+fn python_call() {
+    let value = r#"<?xml version='1.0'?>
+<methodCall>
+<methodName>examples.getStateName</methodName>
+<params>
+<param>
+<value><int>41</int></value>
+</param>
+<param>
+<value><struct>
+<member>
+<name>enabled</name>
+<value><boolean>1</boolean></value>
+</member>
+<member>
+<name>ratio</name>
+<value><double>0.5</double></value>
+</member>
+<member>
+<name>created</name>
+<value><dateTime.iso8601>20240101T12:30:00</dateTime.iso8601></value>
+</member>
+<member>
+<name>data</name>
+<value><base64>
+aGVsbG8gd29ybGQgaGVsbG8gd29ybGQgaGVsbG8gd29ybGQgaGVsbG8gd29ybGQgaGVsbG8gd29y
+bGQ=
+</base64></value>
+</member>
+<member>
+<name>tags</name>
+<value><array><data>
+<value><string>a</string></value>
+<value><string>b &amp; c</string></value>
+</data></array></value>
+</member>
+</struct></value>
+</param>
+</params>
+</methodCall>
+"#;
+
+    let created: DateTime = "20240101T12:30:00".parse().unwrap();
+    let data = "hello world ".repeat(5).trim_end().as_bytes().to_vec();
+    let expected = MethodCall::new(
+        "examples.getStateName",
+        vec![
+            value!(41).unwrap(),
+            value!({"enabled": true, "ratio": 0.5, "created": created, "data": data, "tags": ["a", "b & c"]}).unwrap(),
+        ],
+    );
+
+    assert_same_call(&from_str::<MethodCall>(value).unwrap(), &expected);
+}
+
+// Python: xmlrpc.client.dumps(xmlrpc.client.Fault(1, ...), methodresponse=True)
+#[test]
+fn python_fault() {
+    let value = r#"<?xml version='1.0'?>
+<methodResponse>
+<fault>
+<value><struct>
+<member>
+<name>faultCode</name>
+<value><int>1</int></value>
+</member>
+<member>
+<name>faultString</name>
+<value><string>&lt;class 'Exception'&gt;:method "foo" is not supported</string></value>
+</member>
+</struct></value>
+</fault>
+</methodResponse>
+"#;
+
+    let fault = Fault::try_from(from_str::<FaultResponse>(value).unwrap()).unwrap();
+    assert_eq!(
+        fault,
+        Fault::new(1, String::from(r#"<class 'Exception'>:method "foo" is not supported"#))
+    );
+}
+
+// Python: xmlrpc.client.dumps((None,), methodresponse=True, allow_none=True)
+#[cfg(feature = "nil")]
+#[test]
+fn python_nil() {
+    let value = r#"<?xml version='1.0'?>
+<methodResponse>
+<params>
+<param>
+<value><nil/></value></param>
+</params>
+</methodResponse>
+"#;
+
+    assert_eq!(from_str::<MethodResponse>(value).unwrap().inner(), Value::nil());
+}
+
+// PHP: xmlrpc_encode_request("method", [...]) from the xmlrpc extension (xmlrpc-epi)
+#[test]
+fn php_epi_call() {
+    let value = b"<?xml version=\"1.0\" encoding=\"iso-8859-1\"?>
+<methodCall>
+<methodName>method</methodName>
+<params>
+ <param>
+  <value>
+   <struct>
+    <member>
+     <name>name</name>
+     <value>
+      <string>caf\xE9 &#233;</string>
+     </value>
+    </member>
+    <member>
+     <name>count</name>
+     <value>
+      <int>3</int>
+     </value>
+    </member>
+   </struct>
+  </value>
+ </param>
+</params>
+</methodCall>
+";
+
+    let expected = MethodCall::new("method", vec![value!({"name": "café é", "count": 3}).unwrap()]);
+    assert_same_call(&from_bytes::<MethodCall>(value).unwrap(), &expected);
+}
+
+// PHP: phpxmlrpc library response
+#[test]
+fn php_phpxmlrpc_response() {
+    let value = r#"<?xml version="1.0"?>
+<methodResponse>
+<params>
+<param>
+<value><array>
+<data>
+<value><i4>1</i4></value>
+<value><string>two</string></value>
+<value><struct>
+</struct></value>
+</data>
+</array></value>
+</param>
+</params>
+</methodResponse>"#;
+
+    assert_eq!(
+        from_str::<MethodResponse>(value).unwrap().inner(),
+        value!([1, "two", {}]).unwrap()
+    );
+}
+
+// Java: Apache XML-RPC (ws-xmlrpc 3.x), which writes untyped strings
+#[test]
+fn java_response() {
+    let value = r#"<?xml version="1.0" encoding="UTF-8"?><methodResponse xmlns:ex="http://ws.apache.org/xmlrpc/namespaces/extensions"><params><param><value><struct><member><name>name</name><value>java</value></member><member><name>id</name><value><i4>7</i4></value></member></struct></value></param></params></methodResponse>"#;
+
+    assert_same(
+        &from_bytes::<MethodResponse>(value.as_bytes()).unwrap().inner(),
+        &value!({"name": "java", "id": 7}).unwrap(),
+    );
+}
+
+// Java: Apache XML-RPC (ws-xmlrpc 3.x) with vendor extensions enabled
+#[cfg(all(feature = "i8", feature = "nil"))]
+#[test]
+fn java_extensions() {
+    let value = r#"<?xml version="1.0" encoding="UTF-8"?><methodResponse xmlns:ex="http://ws.apache.org/xmlrpc/namespaces/extensions"><params><param><value><array><data><value><ex:i8>8589934592</ex:i8></value><value><ex:nil/></value></data></array></value></param></params></methodResponse>"#;
+
+    assert_eq!(
+        from_str::<MethodResponse>(value).unwrap().inner(),
+        Value::array(crate::values::Array::new(vec![Value::i8(1 << 33), Value::nil()]))
+    );
+}
+
+// Java: Apache XML-RPC (ws-xmlrpc 3.x) fault response
+#[test]
+fn java_fault() {
+    let value = r#"<?xml version="1.0" encoding="UTF-8"?><methodResponse xmlns:ex="http://ws.apache.org/xmlrpc/namespaces/extensions"><fault><value><struct><member><name>faultCode</name><value><i4>0</i4></value></member><member><name>faultString</name><value>No such handler: foo.bar</value></member></struct></value></fault></methodResponse>"#;
+
+    let fault = Fault::try_from(from_str::<FaultResponse>(value).unwrap()).unwrap();
+    assert_eq!(fault, Fault::new(0, String::from("No such handler: foo.bar")));
+}
+
+// Perl: RPC::XML
+#[test]
+fn perl_rpc_xml_response() {
+    let value = br#"<?xml version="1.0" encoding="us-ascii"?><methodResponse><params><param><value><struct><member><name>id</name><value><int>42</int></value></member><member><name>name</name><value><string>perl</string></value></member><member><name>ok</name><value><boolean>0</boolean></value></member></struct></value></param></params></methodResponse>"#;
+
+    assert_eq!(
+        from_bytes::<MethodResponse>(value).unwrap().inner(),
+        value!({"id": 42, "name": "perl", "ok": false}).unwrap()
+    );
+}
+
+// Perl: Frontier::RPC2
+#[test]
+fn perl_frontier_call() {
+    let value = r#"<?xml version="1.0"?>
+<methodCall>
+<methodName>echo</methodName>
+<params>
+<param><value><string>Hello</string></value></param>
+<param><value><i4>-5</i4></value></param>
+<param><value><double>2.5</double></value></param>
+</params>
+</methodCall>
+"#;
+
+    let expected = MethodCall::new(
+        "echo",
+        vec![value!("Hello").unwrap(), value!(-5).unwrap(), value!(2.5).unwrap()],
+    );
+    assert_same_call(&from_str::<MethodCall>(value).unwrap(), &expected);
+}
@@ -0,0 +1,95 @@
+use crate::values::{MethodCall, Value};
+use crate::xml::deserialize_xml as from_str;
+
+#[test]
+fn reject_doctype() {
+    let value = "<!DOCTYPE value><value><string>foo</string></value>";
+    let error = from_str::<Value>(value).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse DTD: DOCTYPE declarations are not supported"
+    );
+}
+
+#[test]
+fn reject_doctype_entities() {
+    let value = r#"<?xml version="1.0"?>
+<!DOCTYPE lolz [
+  <!ENTITY lol "lol">
+  <!ENTITY lol1 "&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;">
+  <!ENTITY lol2 "&lol1;&lol1;&lol1;&lol1;&lol1;&lol1;&lol1;&lol1;&lol1;&lol1;">
+]>
+<methodCall><methodName>&lol2;</methodName></methodCall>"#;
+    assert!(from_str::<MethodCall>(value).is_err());
+}
+
+#[test]
+fn reject_external_entities() {
+    let value = r#"<!DOCTYPE value [<!ENTITY xxe SYSTEM "file:///etc/passwd">]><value><string>&xxe;</string></value>"#;
+    assert!(from_str::<Value>(value).is_err());
+}
+
+#[test]
+fn reject_unknown_entities() {
+    let value = "<value><string>&nbsp;</string></value>";
+    assert!(from_str::<Value>(value).is_err());
+}
+
+#[test]
+fn predefined_entities() {
+    let value = "<value><string>&lt;&gt;&amp;&apos;&quot;&#233;&#x20AC;</string></value>";
+    assert_eq!(
+        from_str::<Value>(value).unwrap(),
+        Value::string(String::from("<>&'\"é€"))
+    );
+}
+
+#[test]
+fn cdata() {
+    let value = "<value><string><![CDATA[<b>&amp;</b>]]></string></value>";
+    assert_eq!(
+        from_str::<Value>(value).unwrap(),
+        Value::string(String::from("<b>&amp;</b>"))
+    );
+}
+
+#[test]
+fn cdata_mixed() {
+    let value = "<value><string>a &lt; <![CDATA[<b>]]> &gt; c</string></value>";
+    assert_eq!(
+        from_str::<Value>(value).unwrap(),
+        Value::string(String::from("a < <b> > c"))
+    );
+}
+
+#[test]
+fn cdata_untyped() {
+    let value = "<value><![CDATA[<b>]]></value>";
+    assert_eq!(from_str::<Value>(value).unwrap(), Value::string(String::from("<b>")));
+}
+
+#[test]
+fn comments() {
+    let value = "<!-- a --><value><!-- b --><struct><!-- c --><member><!-- d --><name>foo</name><!-- e --><value><array><!-- f --><data><!-- g --><value><i4>1</i4></value><!-- h --></data></array></value></member></struct><!-- i --></value><!-- j -->";
+    let expected = Value::structure(crate::values::Struct::new(vec![crate::values::Member::new(
+        String::from("foo"),
+        Value::array(crate::values::Array::new(vec![Value::i4(1)])),
+    )]));
+    assert_eq!(from_str::<Value>(value).unwrap(), expected);
+}
+
+#[test]
+fn comments_in_text() {
+    let value = "<value><string>foo<!-- comment -->bar</string></value>";
+    assert_eq!(from_str::<Value>(value).unwrap(), Value::string(String::from("foobar")));
+
+    let value = "<value>foo<!-- comment -->bar</value>";
+    assert_eq!(from_str::<Value>(value).unwrap(), Value::string(String::from("foobar")));
+}
+
+#[test]
+fn processing_instructions() {
+    let value = r#"<?xml version="1.0"?><?xml-stylesheet href="rpc.css"?><methodCall><?a?><methodName>foo</methodName><params><?b?><param><?c?><value><?d?><i4>1</i4></value></param></params></methodCall><?e?>"#;
+    let expected = MethodCall::new("foo", vec![Value::i4(1)]);
+    assert_eq!(from_str::<MethodCall>(value).unwrap(), expected);
+}
@@ -0,0 +1,61 @@
+use crate::{deserialize_xml, serialize_xml, serialize_xml_with_options, value, MethodCall, XmlOptions};
+
+#[test]
+fn default_options() {
+    let call = MethodCall::new("add", vec![value!(1).unwrap(), value!(2).unwrap()]);
+
+    assert_eq!(
+        serialize_xml_with_options(&call, &XmlOptions::new()).unwrap(),
+        serialize_xml(&call).unwrap()
+    );
+}
+
+#[test]
+fn declaration() {
+    let value = value!(1).unwrap();
+    let options = XmlOptions::new().declaration(true);
+
+    assert_eq!(
+        serialize_xml_with_options(&value, &options).unwrap(),
+        r#"<?xml version="1.0"?><value><i4>1</i4></value>"#
+    );
+}
+
+#[test]
+fn indent() {
+    let call = MethodCall::new("add", vec![value!(1).unwrap(), value!("").unwrap()]);
+    let options = XmlOptions::new().indent(' ', 2).declaration(true);
+
+    let expected = r#"<?xml version="1.0"?>
+<methodCall>
+  <methodName>add</methodName>
+  <params>
+    <param>
+      <value>
+        <i4>1</i4>
+      </value>
+    </param>
+    <param>
+      <value>
+        <string></string>
+      </value>
+    </param>
+  </params>
+</methodCall>"#;
+
+    assert_eq!(serialize_xml_with_options(&call, &options).unwrap(), expected);
+}
+
+#[test]
+fn indent_roundtrip() {
+    let call = MethodCall::new(
+        "hello",
+        vec![value!({"name": "padded", "ids": [1, 2], "empty": ""}).unwrap()],
+    );
+    let options = XmlOptions::new().indent('\t', 1);
+
+    let xml = serialize_xml_with_options(&call, &options).unwrap();
+    let parsed: MethodCall = deserialize_xml(&xml).unwrap();
+
+    assert_eq!(parsed, call);
+}
@@ -35,3 +35,60 @@ fn from_method_response_fault() {
 
     assert_eq!(from_str::<FaultResponse>(value).unwrap(), expected);
 }
+
+#[test]
+fn to_method_response_fault_with_members() {
+    let fault = Fault::new(4, String::from("Too many parameters."))
+        .with_member(String::from("faultDetail"), Value::string(String::from("expected 2")));
+    let value = FaultResponse::from(fault);
+    let expected = "<methodResponse><fault><value><struct><member><name>faultCode</name><value><i4>4</i4></value></member><member><name>faultDetail</name><value><string>expected 2</string></value></member><member><name>faultString</name><value><string>Too many parameters.</string></value></member></struct></value></fault></methodResponse>";
+
+    assert_eq!(to_string(&value).unwrap(), expected);
+}
+
+#[test]
+fn to_method_response_fault_with_members_sorted() {
+    let fault = Fault::new(4, String::from("Too many parameters."))
+        .with_member(String::from("zeta"), Value::i4(3))
+        .with_member(String::from("alpha"), Value::i4(1))
+        .with_member(String::from("mu"), Value::i4(2));
+
+    let names: Vec<&str> = fault.members().keys().map(String::as_str).collect();
+    assert_eq!(names, vec!["alpha", "mu", "zeta"]);
+
+    let value = FaultResponse::from(fault);
+    let expected = "<methodResponse><fault><value><struct><member><name>alpha</name><value><i4>1</i4></value></member><member><name>faultCode</name><value><i4>4</i4></value></member><member><name>faultString</name><value><string>Too many parameters.</string></value></member><member><name>mu</name><value><i4>2</i4></value></member><member><name>zeta</name><value><i4>3</i4></value></member></struct></value></fault></methodResponse>";
+
+    assert_eq!(to_string(&value).unwrap(), expected);
+}
+
+#[test]
+fn from_method_response_fault_with_members() {
+    let value = "<methodResponse><fault><value><struct><member><name>faultString</name><value><string>Too many parameters.</string></value></member><member><name>faultDetail</name><value><string>expected 2</string></value></member><member><name>faultCode</name><value><i4>4</i4></value></member></struct></value></fault></methodResponse>";
+    let fault = Fault::try_from(from_str::<FaultResponse>(value).unwrap()).unwrap();
+
+    assert_eq!(fault.code(), 4);
+    assert_eq!(fault.string(), "Too many parameters.");
+    assert_eq!(fault.member::<String>("faultDetail").unwrap(), "expected 2");
+    assert_eq!(fault.member::<Option<i32>>("faultTrace").unwrap(), None);
+    assert!(fault.member::<String>("faultTrace").is_err());
+}
+
+#[test]
+fn from_method_response_fault_string_code() {
+    let value = "<methodResponse><fault><value><struct><member><name>faultCode</name><value><string> 4 </string></value></member><member><name>faultString</name><value><string>Too many parameters.</string></value></member></struct></value></fault></methodResponse>";
+    let fault = Fault::try_from(from_str::<FaultResponse>(value).unwrap()).unwrap();
+
+    assert_eq!(fault, Fault::new(4, String::from("Too many parameters.")));
+}
+
+#[test]
+fn from_method_response_fault_invalid() {
+    let invalid_code = "<methodResponse><fault><value><struct><member><name>faultCode</name><value><string>four</string></value></member><member><name>faultString</name><value><string>Too many parameters.</string></value></member></struct></value></fault></methodResponse>";
+    let missing_string = "<methodResponse><fault><value><struct><member><name>faultCode</name><value><i4>4</i4></value></member></struct></value></fault></methodResponse>";
+
+    assert!(Fault::try_from(from_str::<FaultResponse>(invalid_code).unwrap()).is_err());
+    assert!(Fault::try_from(from_str::<FaultResponse>(missing_string).unwrap())
+        .unwrap_err()
+        .is_missing_field());
+}
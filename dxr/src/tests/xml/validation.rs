@@ -0,0 +1,174 @@
+use crate::values::{MethodCall, Value};
+use crate::xml::{deserialize_xml_with_options as from_str, serialize_xml_with_options as to_string, XmlOptions};
+use crate::Validation;
+
+fn lenient() -> XmlOptions {
+    XmlOptions::new().validation(Validation::Lenient)
+}
+
+fn standard() -> XmlOptions {
+    XmlOptions::new()
+}
+
+fn strict() -> XmlOptions {
+    XmlOptions::new().validation(Validation::Strict)
+}
+
+#[test]
+fn booleans() {
+    for (xml, expected) in [
+        ("<value><boolean>1</boolean></value>", true),
+        ("<value><boolean>0</boolean></value>", false),
+    ] {
+        for options in [lenient(), standard(), strict()] {
+            assert_eq!(from_str::<Value>(xml, &options).unwrap(), Value::boolean(expected));
+        }
+    }
+
+    for (xml, expected) in [
+        ("<value><boolean>true</boolean></value>", true),
+        ("<value><boolean>FALSE</boolean></value>", false),
+        ("<value><boolean> True </boolean></value>", true),
+    ] {
+        assert_eq!(from_str::<Value>(xml, &lenient()).unwrap(), Value::boolean(expected));
+        assert!(from_str::<Value>(xml, &standard()).is_err());
+        assert!(from_str::<Value>(xml, &strict()).is_err());
+    }
+
+    let xml = "<value><boolean> 1 </boolean></value>";
+    assert_eq!(from_str::<Value>(xml, &standard()).unwrap(), Value::boolean(true));
+    assert!(from_str::<Value>(xml, &strict()).is_err());
+
+    let xml = "<value><boolean>yes</boolean></value>";
+    assert!(from_str::<Value>(xml, &lenient()).is_err());
+}
+
+#[test]
+fn lenient_is_scoped() {
+    let xml = "<value><boolean>true</boolean></value>";
+    assert!(from_str::<Value>(xml, &lenient()).is_ok());
+    assert!(crate::deserialize_xml::<Value>(xml).is_err());
+}
+
+#[test]
+fn lenient_nested_booleans() {
+    let xml = "<methodCall><methodName>set</methodName><params>\
+        <param><value><struct><member><name>enabled</name><value><boolean>TRUE</boolean></value></member></struct></value></param>\
+        <param><value><array><data><value><boolean>false</boolean></value><value><string>true</string></value></data></array></value></param>\
+        </params></methodCall>";
+
+    let call = from_str::<MethodCall>(xml, &lenient()).unwrap();
+    assert_eq!(
+        call.params(),
+        vec![
+            crate::value!({"enabled": true}).unwrap(),
+            crate::value!([false, "true"]).unwrap(),
+        ]
+    );
+
+    assert!(from_str::<MethodCall>(xml, &standard()).is_err());
+}
+
+#[test]
+fn integers() {
+    for xml in [
+        "<value><i4>42</i4></value>",
+        "<value><i4>+42</i4></value>",
+        "<value><int>0042</int></value>",
+    ] {
+        assert_eq!(from_str::<Value>(xml, &standard()).unwrap(), Value::i4(42));
+        assert_eq!(from_str::<Value>(xml, &strict()).unwrap(), Value::i4(42));
+    }
+
+    for xml in ["<value><i4> 42</i4></value>", "<value><i4>42\n</i4></value>"] {
+        assert_eq!(from_str::<Value>(xml, &lenient()).unwrap(), Value::i4(42));
+        assert_eq!(from_str::<Value>(xml, &standard()).unwrap(), Value::i4(42));
+        assert_eq!(
+            from_str::<Value>(xml, &strict()).unwrap_err().to_string(),
+            format!(
+                "Invalid <i4> value: {:?}",
+                xml.trim_start_matches("<value><i4>").trim_end_matches("</i4></value>")
+            )
+        );
+    }
+
+    for xml in [
+        "<value><i4>+-1</i4></value>",
+        "<value><i4></i4></value>",
+        "<value><i4/></value>",
+    ] {
+        assert!(from_str::<Value>(xml, &strict()).is_err());
+    }
+}
+
+#[cfg(feature = "i8")]
+#[test]
+fn longs() {
+    let xml = "<value><i8>-8589934592</i8></value>";
+    assert_eq!(from_str::<Value>(xml, &strict()).unwrap(), Value::i8(-(1 << 33)));
+
+    let xml = "<value><i8> 1</i8></value>";
+    assert!(from_str::<Value>(xml, &strict()).is_err());
+}
+
+#[test]
+fn doubles() {
+    for (xml, expected) in [
+        ("<value><double>1.5</double></value>", 1.5),
+        ("<value><double>-0.5</double></value>", -0.5),
+        ("<value><double>+.5</double></value>", 0.5),
+        ("<value><double>2</double></value>", 2.0),
+    ] {
+        assert_eq!(from_str::<Value>(xml, &strict()).unwrap(), Value::double(expected));
+    }
+
+    for xml in [
+        "<value><double>1e5</double></value>",
+        "<value><double>inf</double></value>",
+        "<value><double>NaN</double></value>",
+        "<value><double> 1.5</double></value>",
+        "<value><double>.</double></value>",
+    ] {
+        assert!(from_str::<Value>(xml, &strict()).is_err(), "{xml}");
+    }
+
+    let xml = "<value><double>1e5</double></value>";
+    assert_eq!(from_str::<Value>(xml, &standard()).unwrap(), Value::double(1e5));
+}
+
+#[test]
+fn strings_are_unaffected() {
+    let xml = "<value><string>true 01 1e5</string></value>";
+    let expected = Value::string(String::from("true 01 1e5"));
+
+    for options in [lenient(), standard(), strict()] {
+        assert_eq!(from_str::<Value>(xml, &options).unwrap(), expected);
+    }
+}
+
+#[test]
+fn method_names() {
+    for name in ["system.listMethods", "a_b:c/d", "X1"] {
+        let xml = format!("<methodCall><methodName>{name}</methodName></methodCall>");
+        assert_eq!(from_str::<MethodCall>(&xml, &strict()).unwrap().name(), name);
+    }
+
+    for name in ["a b", "grüße", "a-b", "a!"] {
+        let xml = format!("<methodCall><methodName>{name}</methodName></methodCall>");
+        assert_eq!(from_str::<MethodCall>(&xml, &standard()).unwrap().name(), name);
+        assert!(from_str::<MethodCall>(&xml, &strict()).is_err(), "{name}");
+    }
+}
+
+#[test]
+fn serialize_method_names() {
+    let call = MethodCall::new("a b", vec![]);
+    assert!(to_string(&call, &standard()).is_ok());
+    assert_eq!(
+        to_string(&call, &strict()).unwrap_err().to_string(),
+        r#"Invalid <methodName> value: "a b""#
+    );
+
+    let call = MethodCall::new("system.listMethods", vec![Value::i4(-1), Value::boolean(true)]);
+    assert!(to_string(&call, &strict().indent(' ', 2)).is_ok());
+}
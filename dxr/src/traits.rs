@@ -1,6 +1,11 @@
 use crate::error::DxrError;
+use crate::fault::Fault;
 use crate::values::Value;
 
+// imports for intra-doc links
+#[cfg(doc)]
+use crate::params::Defaulted;
+
 /// Trait for converting from Rust values to XML-RPC values.
 pub trait TryToValue: Sized {
     /// Fallible conversion method from Rust values into XML-RPC values.
@@ -20,6 +25,36 @@ pub trait TryFromValue: Sized {
     /// will fail (missing struct members, type mismatches, or mismatch with the expected length of
     /// an array or tuple).
     fn try_from_value(value: &Value) -> Result<Self, DxrError>;
+
+    /// Fallback for missing values.
+    ///
+    /// This method is called when converting method call parameters into tuples, if there are
+    /// fewer parameters than tuple members. Types that return `Some` here (like [`Option<T>`]
+    /// and [`Defaulted<T>`]) can be used as optional trailing tuple members. The default
+    /// implementation returns `None`, i.e. the value is required.
+    fn try_from_missing() -> Option<Self> {
+        None
+    }
+}
+
+/// Trait for converting from XML-RPC values to Rust values that borrow from them.
+///
+/// This trait is implemented for all types that implement [`TryFromValue`], and additionally for
+/// references like `&str` (for `string` values) and `&[u8]` (for `base64` values), which can be
+/// borrowed directly from the [`Value`] without copying their contents.
+pub trait TryBorrowFromValue<'a>: Sized {
+    /// Fallible conversion method from borrowed XML-RPC values into Rust values.
+    ///
+    /// This method fails under the same conditions as [`TryFromValue::try_from_value`].
+    fn try_borrow_from_value(value: &'a Value) -> Result<Self, DxrError>;
+
+    /// Fallback for missing values.
+    ///
+    /// This works like [`TryFromValue::try_from_missing`] (and returns the same value for all
+    /// types that implement [`TryFromValue`]).
+    fn try_borrow_from_missing() -> Option<Self> {
+        None
+    }
 }
 
 /// Trait for converting from Rust values to XML-RPC method call arguments.
@@ -49,3 +84,81 @@ pub trait TryFromParams: Sized {
     ///   target type. This returns an error if any list value does not match the target type.
     fn try_from_params(values: &[Value]) -> Result<Self, DxrError>;
 }
+
+/// Trait for Rust types that correspond to a known XML-RPC value type.
+///
+/// The type names are used for describing method signatures (i.e. for the `system.methodSignature`
+/// method of the introspection API), and for checking the types of method call parameters before
+/// they are converted. This trait can be derived for structs (which are represented as `struct`
+/// values).
+pub trait XmlRpcType {
+    /// Name of the XML-RPC value type (i.e. `"i4"`, `"string"`, `"array"`, or `"struct"`).
+    ///
+    /// Types that can represent values of any type (like [`Value`]) use the name `"any"`.
+    fn xmlrpc_type() -> &'static str;
+
+    /// Check whether a value has a type that is compatible with this type.
+    ///
+    /// The default implementation compares [`Value::type_name`] with [`XmlRpcType::xmlrpc_type`].
+    /// This check only considers the outermost value type, i.e. it does not check the types of
+    /// array items or struct members.
+    fn accepts(value: &Value) -> bool {
+        value.type_name() == Self::xmlrpc_type()
+    }
+}
+
+/// Trait for Rust types that describe XML-RPC method call parameter lists with known value types.
+///
+/// This trait is implemented for the unit type and for tuples of types that implement
+/// [`XmlRpcType`] (including single parameters, i.e. one-tuples like `(i32,)`), optionally
+/// followed by a [`Rest<T>`](crate::Rest) for trailing parameters. It can also be derived for
+/// structs that derive [`TryFromParams`].
+///
+/// Signatures cannot describe parameter lists of variable length, so trailing parameters (i.e.
+/// [`Rest<T>`](crate::Rest), `Vec<T>`, or `#[dxr(variadic)]` fields) are only checked, but not
+/// included in [`XmlRpcParams::xmlrpc_params`].
+pub trait XmlRpcParams {
+    /// Names of the XML-RPC value types of the parameters.
+    fn xmlrpc_params() -> Vec<&'static str>;
+
+    /// Check the number and types of method call parameters.
+    ///
+    /// This returns an error for too many parameters, and for parameters with incompatible types.
+    /// Missing trailing parameters are not rejected here, since they can be optional.
+    fn check_params(values: &[Value]) -> Result<(), DxrError>;
+}
+
+/// Trait for converting from Rust error types to XML-RPC faults.
+///
+/// This trait can be derived for enums with a `#[fault(code = ...)]` attribute on every variant.
+/// The derive macro also implements `From<T> for Fault`, so errors can be propagated with the `?`
+/// operator in method handlers.
+pub trait IntoFault {
+    /// Conversion method from Rust errors into XML-RPC faults.
+    fn into_fault(self) -> Fault;
+}
+
+/// Trait for converting from XML-RPC faults to Rust error types.
+///
+/// This is the inverse of [`IntoFault`], and can be derived for the same enums. It is used for
+/// converting the faults that are returned by a server into a typed error on the client side.
+pub trait TryFromFault: Sized {
+    /// Fallible conversion method from XML-RPC faults into Rust errors.
+    ///
+    /// This returns `Ok(None)` if the fault code is unknown (i.e. for a different kind of fault),
+    /// and an error if the fault is malformed (i.e. if it does not have the additional struct
+    /// members that the target type expects).
+    fn try_from_fault(fault: &Fault) -> Result<Option<Self>, DxrError>;
+}
+
+impl IntoFault for Fault {
+    fn into_fault(self) -> Fault {
+        self
+    }
+}
+
+impl TryFromFault for Fault {
+    fn try_from_fault(fault: &Fault) -> Result<Option<Self>, DxrError> {
+        Ok(Some(fault.clone()))
+    }
+}
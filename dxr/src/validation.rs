@@ -0,0 +1,122 @@
+//! optional validation of XML-RPC documents against the rules of the specification
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+mod lenient;
+pub(crate) use lenient::Lenient;
+
+/// Level of validation that is applied to XML-RPC documents
+///
+/// The XML-RPC specification is stricter than most implementations, both in what they produce
+/// and in what they accept. By default ([`Validation::Standard`]), values are parsed with the rules
+/// of the XML parser and the [`FromStr`](std::str::FromStr) implementations of Rust types: leading
+/// and trailing whitespace is ignored, and integers can have a leading `+` sign and leading zeros.
+///
+/// ```
+/// use dxr::{deserialize_xml_with_options, Value, Validation, XmlOptions};
+///
+/// let xml = "<value><boolean>true</boolean></value>";
+///
+/// let options = XmlOptions::new().validation(Validation::Lenient);
+/// let value: Value = deserialize_xml_with_options(xml, &options).unwrap();
+/// assert_eq!(value, Value::boolean(true));
+///
+/// let options = XmlOptions::new().validation(Validation::Strict);
+/// assert!(deserialize_xml_with_options::<Value>(xml, &options).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Validation {
+    /// accept commonly produced deviations from the specification
+    ///
+    /// In addition to the deviations that are accepted by [`Validation::Standard`], `<boolean>`
+    /// values can also be `true` or `false` (case-insensitive).
+    Lenient,
+    /// parse values with the rules of the XML parser and the Rust standard library
+    #[default]
+    Standard,
+    /// check documents against the rules of the XML-RPC specification
+    ///
+    /// This rejects method names with characters other than ASCII letters, digits, and the
+    /// characters `_`, `.`, `:`, and `/`, and any whitespace in `<i4>`, `<int>`, `<i8>`,
+    /// `<boolean>`, and `<double>` values. Integers must consist of an optional sign followed by
+    /// digits, booleans must be either `0` or `1`, and doubles must be written in decimal point
+    /// notation (without exponents, infinity, or NaN). Serialized method names are checked as well.
+    Strict,
+}
+
+// check the raw contents of elements that have stricter rules in the specification
+pub(crate) fn validate_strict(xml: &str) -> Result<(), String> {
+    let mut reader = Reader::from_str(xml);
+    let mut element: Option<Vec<u8>> = None;
+    let mut text = String::new();
+
+    loop {
+        match reader.read_event().map_err(|error| error.to_string())? {
+            Event::Start(start) => {
+                element = Some(start.local_name().as_ref().to_vec());
+                text.clear();
+            },
+            Event::Empty(empty) => check(empty.local_name().as_ref(), "")?,
+            Event::Text(contents) => text.push_str(&contents.unescape().map_err(|error| error.to_string())?),
+            Event::CData(contents) => text.push_str(std::str::from_utf8(&contents).map_err(|error| error.to_string())?),
+            Event::End(_) => {
+                if let Some(name) = element.take() {
+                    check(&name, &text)?;
+                }
+            },
+            Event::Eof => return Ok(()),
+            _ => {},
+        }
+    }
+}
+
+fn check(element: &[u8], text: &str) -> Result<(), String> {
+    let valid = match element {
+        b"methodName" => is_method_name(text),
+        b"i4" | b"int" | b"i8" => is_integer(text),
+        b"boolean" => text == "0" || text == "1",
+        b"double" => is_double(text),
+        _ => return Ok(()),
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid <{}> value: {text:?}",
+            String::from_utf8_lossy(element)
+        ))
+    }
+}
+
+fn is_method_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, b'_' | b'.' | b':' | b'/'))
+}
+
+fn is_digits(digits: &str) -> bool {
+    !digits.is_empty() && digits.bytes().all(|c| c.is_ascii_digit())
+}
+
+fn strip_sign(number: &str) -> &str {
+    number
+        .strip_prefix('+')
+        .or_else(|| number.strip_prefix('-'))
+        .unwrap_or(number)
+}
+
+fn is_integer(integer: &str) -> bool {
+    is_digits(strip_sign(integer))
+}
+
+fn is_double(double: &str) -> bool {
+    match strip_sign(double).split_once('.') {
+        Some(("", fraction)) => is_digits(fraction),
+        Some((integer, "")) => is_digits(integer),
+        Some((integer, fraction)) => is_digits(integer) && is_digits(fraction),
+        None => is_digits(strip_sign(double)),
+    }
+}
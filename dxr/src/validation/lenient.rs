@@ -0,0 +1,310 @@
+//! deserializer wrapper that passes [`Validation::Lenient`](super::Validation::Lenient) down to
+//! the contents of `<boolean>` elements
+//!
+//! The wrapper is transparent for everything except the text of `<boolean>` values, where `true`
+//! and `false` (case-insensitive) are replaced with `1` and `0` before they reach the visitor.
+//! Elements are recognized by the names of the map keys and enum variants that they are
+//! deserialized from.
+
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+use serde::de::{DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor};
+
+// position of the wrapped deserializer in the document
+#[derive(Clone)]
+enum Context {
+    // any value (booleans are not modified)
+    Value,
+    // contents of a <boolean> element
+    Boolean,
+    // name of a map key or enum variant (i.e. of an element), which is recorded to find <boolean> values
+    Name(Rc<Cell<bool>>),
+}
+
+pub(crate) struct Lenient<D> {
+    inner: D,
+    context: Context,
+}
+
+impl<D> Lenient<D> {
+    pub(crate) fn new(inner: D) -> Self {
+        Lenient::with_context(inner, Context::Value)
+    }
+
+    fn with_context(inner: D, context: Context) -> Self {
+        Lenient { inner, context }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Self::Error> {
+                self.inner.$method($($arg,)* LenientVisitor::new(visitor, self.context))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Lenient<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+struct LenientVisitor<V> {
+    inner: V,
+    context: Context,
+}
+
+impl<V> LenientVisitor<V> {
+    fn new(inner: V, context: Context) -> Self {
+        LenientVisitor { inner, context }
+    }
+
+    // replace lenient boolean values, and record the names of map keys and enum variants
+    fn check(&self, s: &str) -> Option<&'static str> {
+        match &self.context {
+            Context::Boolean if s.eq_ignore_ascii_case("true") => Some("1"),
+            Context::Boolean if s.eq_ignore_ascii_case("false") => Some("0"),
+            Context::Name(boolean) => {
+                boolean.set(s == "boolean");
+                None
+            },
+            _ => None,
+        }
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: serde::de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for LenientVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        match self.check(v) {
+            Some(replaced) => self.inner.visit_str(replaced),
+            None => self.inner.visit_str(v),
+        }
+    }
+
+    fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        match self.check(v) {
+            Some(replaced) => self.inner.visit_str(replaced),
+            None => self.inner.visit_borrowed_str(v),
+        }
+    }
+
+    fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Self::Value, E> {
+        match self.check(&v) {
+            Some(replaced) => self.inner.visit_str(replaced),
+            None => self.inner.visit_string(v),
+        }
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.visit_some(Lenient::with_context(deserializer, self.context))
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner
+            .visit_newtype_struct(Lenient::with_context(deserializer, self.context))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_seq(LenientAccess::new(seq, false))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_map(LenientAccess::new(map, false))
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_enum(LenientAccess::new(data, false))
+    }
+}
+
+struct LenientSeed<S> {
+    inner: S,
+    context: Context,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for LenientSeed<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner
+            .deserialize(Lenient::with_context(deserializer, self.context))
+    }
+}
+
+struct LenientAccess<A> {
+    inner: A,
+    // whether the next value (or the contents of this variant) is a <boolean> value
+    boolean: bool,
+}
+
+impl<A> LenientAccess<A> {
+    fn new(inner: A, boolean: bool) -> Self {
+        LenientAccess { inner, boolean }
+    }
+
+    fn seed<S>(&self, inner: S) -> LenientSeed<S> {
+        let context = if self.boolean { Context::Boolean } else { Context::Value };
+        LenientSeed { inner, context }
+    }
+
+    fn name_seed<S>(inner: S, boolean: &Rc<Cell<bool>>) -> LenientSeed<S> {
+        let context = Context::Name(Rc::clone(boolean));
+        LenientSeed { inner, context }
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for LenientAccess<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> {
+        let seed = self.seed(seed);
+        self.inner.next_element_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for LenientAccess<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        let boolean = Rc::new(Cell::new(false));
+        let key = self.inner.next_key_seed(Self::name_seed(seed, &boolean))?;
+        self.boolean = boolean.get();
+        Ok(key)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        let seed = self.seed(seed);
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: EnumAccess<'de>> EnumAccess<'de> for LenientAccess<A> {
+    type Error = A::Error;
+    type Variant = LenientAccess<A::Variant>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let boolean = Rc::new(Cell::new(false));
+        let (value, variant) = self.inner.variant_seed(Self::name_seed(seed, &boolean))?;
+        Ok((value, LenientAccess::new(variant, boolean.get())))
+    }
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for LenientAccess<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Self::Error> {
+        let seed = self.seed(seed);
+        self.inner.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        self.inner
+            .tuple_variant(len, LenientVisitor::new(visitor, Context::Value))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.inner
+            .struct_variant(fields, LenientVisitor::new(visitor, Context::Value))
+    }
+}
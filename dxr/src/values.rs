@@ -1,8 +1,21 @@
+mod canonical;
+pub use canonical::CanonicalValue;
+
 mod datetime;
 pub use datetime::DateTime;
 pub use datetime::DateTimeParseError;
 
+mod diff;
+pub use diff::Difference;
+
+mod display;
+
+mod pointer;
+
 mod ser_de;
 
 mod types;
 pub use types::*;
+
+mod visit;
+pub use visit::{walk_value, walk_value_mut, Visit, VisitMut};
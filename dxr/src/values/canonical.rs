@@ -0,0 +1,193 @@
+//! canonical representation of XML-RPC values with total equality, hashing, and ordering
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use super::types::{Member, Type, Value};
+
+/// Canonical form of an XML-RPC value that implements [`Eq`], [`Hash`], and [`Ord`]
+///
+/// The [`Value`] type only implements [`PartialEq`], because `<double>` values can be NaN. This
+/// wrapper type defines canonical semantics for comparing values, which makes it possible to use
+/// XML-RPC values as keys in a `HashMap` or `BTreeMap`, to deduplicate them, or to sort them:
+///
+/// - the order of struct members is irrelevant (unlike [`PartialEq`] for [`Value`], which compares
+///   the members of deserialized structs in document order)
+/// - `<double>` values are compared with [`f64::total_cmp`], i.e. NaN is equal to itself, and
+///   `-0.0` is not equal to `0.0`
+/// - values of different types are ordered by type, in the order `<i4>`, `<i8>`, `<boolean>`,
+///   `<string>`, `<double>`, `<dateTime.iso8601>`, `<base64>`, `<struct>`, `<array>`, `<nil/>`
+///   (i.e. an `<i4>` value is never equal to an `<i8>` or `<double>` value with the same number)
+/// - structs are ordered by their members, sorted by name, and arrays are ordered
+///   lexicographically by their items
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use dxr::{value, CanonicalValue};
+///
+/// let mut set = HashSet::new();
+/// set.insert(CanonicalValue::new(value!({"a": 1, "b": f64::NAN}).unwrap()));
+/// set.insert(CanonicalValue::new(value!({"b": f64::NAN, "a": 1}).unwrap()));
+/// assert_eq!(set.len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CanonicalValue(Value);
+
+impl CanonicalValue {
+    /// Construct a new [`CanonicalValue`] from a value.
+    pub fn new(value: Value) -> CanonicalValue {
+        CanonicalValue(value)
+    }
+
+    /// Consume the [`CanonicalValue`] and return the inner value.
+    pub fn into_inner(self) -> Value {
+        self.0
+    }
+}
+
+impl From<Value> for CanonicalValue {
+    fn from(value: Value) -> Self {
+        CanonicalValue(value)
+    }
+}
+
+impl From<CanonicalValue> for Value {
+    fn from(value: CanonicalValue) -> Self {
+        value.0
+    }
+}
+
+impl Deref for CanonicalValue {
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<Value> for CanonicalValue {
+    fn as_ref(&self) -> &Value {
+        &self.0
+    }
+}
+
+impl PartialEq for CanonicalValue {
+    fn eq(&self, other: &Self) -> bool {
+        Canonical(&self.0) == Canonical(&other.0)
+    }
+}
+
+impl Eq for CanonicalValue {}
+
+impl PartialOrd for CanonicalValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CanonicalValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Canonical(&self.0).cmp(&Canonical(&other.0))
+    }
+}
+
+impl Hash for CanonicalValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Canonical(&self.0).hash(state);
+    }
+}
+
+// borrowed canonical view of a value, used for comparing and hashing nested values
+#[derive(Clone, Copy)]
+struct Canonical<'a>(&'a Value);
+
+impl Canonical<'_> {
+    fn rank(&self) -> u8 {
+        match self.0.inner() {
+            Type::Integer(_) => 0,
+            #[cfg(feature = "i8")]
+            Type::Long(_) => 1,
+            Type::Boolean(_) => 2,
+            Type::String(_) => 3,
+            Type::Double(_) => 4,
+            Type::DateTime(_) => 5,
+            Type::Base64(_) => 6,
+            Type::Struct { .. } => 7,
+            Type::Array { .. } => 8,
+            #[cfg(feature = "nil")]
+            Type::Nil => 9,
+        }
+    }
+}
+
+// struct members, sorted by name first and by value second (for duplicate names)
+fn sorted_members(members: &[Member]) -> Vec<(&str, Canonical<'_>)> {
+    let mut members: Vec<(&str, Canonical)> = members
+        .iter()
+        .map(|member| (member.name(), Canonical(member.inner())))
+        .collect();
+    members.sort();
+    members
+}
+
+impl PartialEq for Canonical<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Canonical<'_> {}
+
+impl PartialOrd for Canonical<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Canonical<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0.inner(), other.0.inner()) {
+            (Type::Integer(a), Type::Integer(b)) => a.cmp(b),
+            #[cfg(feature = "i8")]
+            (Type::Long(a), Type::Long(b)) => a.cmp(b),
+            (Type::Boolean(a), Type::Boolean(b)) => a.cmp(b),
+            (Type::String(a), Type::String(b)) => a.cmp(b),
+            (Type::Double(a), Type::Double(b)) => a.total_cmp(b),
+            (Type::DateTime(a), Type::DateTime(b)) => a.cmp(b),
+            (Type::Base64(a), Type::Base64(b)) => a.cmp(b),
+            (Type::Struct { members: a }, Type::Struct { members: b }) => sorted_members(a).cmp(&sorted_members(b)),
+            (Type::Array { data: a }, Type::Array { data: b }) => {
+                a.inner().iter().map(Canonical).cmp(b.inner().iter().map(Canonical))
+            },
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl Hash for Canonical<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rank().hash(state);
+
+        match self.0.inner() {
+            Type::Integer(int) => int.hash(state),
+            #[cfg(feature = "i8")]
+            Type::Long(long) => long.hash(state),
+            Type::Boolean(boolean) => boolean.hash(state),
+            Type::String(string) => string.hash(state),
+            Type::Double(double) => double.to_bits().hash(state),
+            Type::DateTime(datetime) => datetime.hash(state),
+            Type::Base64(bytes) => bytes.hash(state),
+            Type::Struct { members } => sorted_members(members).hash(state),
+            Type::Array { data } => {
+                state.write_usize(data.inner().len());
+                for item in data.inner() {
+                    Canonical(item).hash(state);
+                }
+            },
+            #[cfg(feature = "nil")]
+            Type::Nil => {},
+        }
+    }
+}
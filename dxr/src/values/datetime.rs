@@ -7,7 +7,7 @@ use winnow::error::{ContextError, StrContext, StrContextValue};
 use winnow::token::take;
 use winnow::Parser;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct DateTime {
     year: u16,
     month: u8,
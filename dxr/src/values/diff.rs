@@ -0,0 +1,166 @@
+//! structural comparison of XML-RPC values
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display, Formatter};
+
+use super::pointer::escape;
+use super::types::{Type, Value};
+
+/// Difference between two XML-RPC values, as reported by [`Value::diff`]
+///
+/// Every difference includes the path of the affected value in JSON pointer syntax (see
+/// [`Value::pointer`]), relative to the root of the compared values.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Difference<'a> {
+    /// struct member or array item that is only present in the right value
+    Added {
+        /// path of the added value
+        path: String,
+        /// added value
+        value: &'a Value,
+    },
+    /// struct member or array item that is only present in the left value
+    Removed {
+        /// path of the removed value
+        path: String,
+        /// removed value
+        value: &'a Value,
+    },
+    /// scalar value that has the same type on both sides, but a different value
+    Changed {
+        /// path of the changed value
+        path: String,
+        /// left value
+        left: &'a Value,
+        /// right value
+        right: &'a Value,
+    },
+    /// value that has a different type on both sides
+    TypeChanged {
+        /// path of the changed value
+        path: String,
+        /// left value
+        left: &'a Value,
+        /// right value
+        right: &'a Value,
+    },
+}
+
+impl Difference<'_> {
+    /// Path of the affected value in JSON pointer syntax.
+    pub fn path(&self) -> &str {
+        match self {
+            Difference::Added { path, .. } => path,
+            Difference::Removed { path, .. } => path,
+            Difference::Changed { path, .. } => path,
+            Difference::TypeChanged { path, .. } => path,
+        }
+    }
+}
+
+impl Display for Difference<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Difference::Added { path, value } => write!(f, "+ {path}: {value}"),
+            Difference::Removed { path, value } => write!(f, "- {path}: {value}"),
+            Difference::Changed { path, left, right } => write!(f, "~ {path}: {left} -> {right}"),
+            Difference::TypeChanged { path, left, right } => write!(
+                f,
+                "~ {path}: {left} ({}) -> {right} ({})",
+                left.inner().name(),
+                right.inner().name()
+            ),
+        }
+    }
+}
+
+impl Value {
+    /// Compute the structural differences between two values.
+    ///
+    /// Struct members are matched by name (independent of their order), and array items are
+    /// matched by index. `<double>` values are compared like in [`CanonicalValue`], i.e. NaN is
+    /// equal to itself. If the values are equal, the returned list is empty.
+    ///
+    /// [`CanonicalValue`]: crate::CanonicalValue
+    ///
+    /// ```
+    /// use dxr::{value, Difference};
+    ///
+    /// let left = value!({"id": 1, "tags": ["a"]}).unwrap();
+    /// let right = value!({"id": 2, "tags": ["a", "b"]}).unwrap();
+    ///
+    /// let diff = left.diff(&right);
+    /// assert_eq!(diff.len(), 2);
+    /// assert_eq!(diff[0].path(), "/id");
+    /// assert_eq!(diff[1].path(), "/tags/1");
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a Value) -> Vec<Difference<'a>> {
+        let mut differences = Vec::new();
+        diff_inner(String::new(), self, other, &mut differences);
+        differences
+    }
+}
+
+fn diff_inner<'a>(path: String, left: &'a Value, right: &'a Value, differences: &mut Vec<Difference<'a>>) {
+    match (left.inner(), right.inner()) {
+        (Type::Struct { members: lmembers }, Type::Struct { members: rmembers }) => {
+            // index the members of the right struct by name (the first member wins for duplicates)
+            let mut rindex = HashMap::with_capacity(rmembers.len());
+            for rmember in rmembers {
+                rindex.entry(rmember.name()).or_insert(rmember);
+            }
+            let lnames: HashSet<&str> = lmembers.iter().map(|lmember| lmember.name()).collect();
+
+            for lmember in lmembers {
+                let mpath = format!("{path}/{}", escape(lmember.name()));
+                match rindex.get(lmember.name()) {
+                    Some(rmember) => diff_inner(mpath, lmember.inner(), rmember.inner(), differences),
+                    None => differences.push(Difference::Removed {
+                        path: mpath,
+                        value: lmember.inner(),
+                    }),
+                }
+            }
+            for rmember in rmembers {
+                if !lnames.contains(rmember.name()) {
+                    differences.push(Difference::Added {
+                        path: format!("{path}/{}", escape(rmember.name())),
+                        value: rmember.inner(),
+                    });
+                }
+            }
+        },
+        (Type::Array { data: ldata }, Type::Array { data: rdata }) => {
+            let (litems, ritems) = (ldata.inner(), rdata.inner());
+            for (index, litem) in litems.iter().enumerate() {
+                let ipath = format!("{path}/{index}");
+                match ritems.get(index) {
+                    Some(ritem) => diff_inner(ipath, litem, ritem, differences),
+                    None => differences.push(Difference::Removed {
+                        path: ipath,
+                        value: litem,
+                    }),
+                }
+            }
+            for (index, ritem) in ritems.iter().enumerate().skip(litems.len()) {
+                differences.push(Difference::Added {
+                    path: format!("{path}/{index}"),
+                    value: ritem,
+                });
+            }
+        },
+        (ltype, rtype) if ltype.name() != rtype.name() => {
+            differences.push(Difference::TypeChanged { path, left, right })
+        },
+        (ltype, rtype) if !scalar_eq(ltype, rtype) => differences.push(Difference::Changed { path, left, right }),
+        _ => {},
+    }
+}
+
+// doubles are compared by their bit patterns, like in CanonicalValue (i.e. NaN is equal to itself)
+fn scalar_eq(left: &Type, right: &Type) -> bool {
+    match (left, right) {
+        (Type::Double(ldouble), Type::Double(rdouble)) => ldouble.total_cmp(rdouble).is_eq(),
+        _ => left == right,
+    }
+}
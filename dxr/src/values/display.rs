@@ -0,0 +1,92 @@
+//! compact human-readable representation of XML-RPC values and method calls / responses
+//!
+//! The format is modeled after the `repr` of the equivalent values in the Python `xmlrpc`
+//! standard library module, for example `{'name': 'foo', 'ids': [1, 2, 3], 'enabled': True}`.
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use super::types::{MethodCall, MethodResponse, Type, Value};
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.inner() {
+            Type::Integer(int) => write!(f, "{int}"),
+            #[cfg(feature = "i8")]
+            Type::Long(long) => write!(f, "{long}"),
+            Type::Boolean(true) => f.write_str("True"),
+            Type::Boolean(false) => f.write_str("False"),
+            Type::String(string) => write_str(f, string),
+            Type::Double(double) => write!(f, "{double:?}"),
+            Type::DateTime(datetime) => write!(f, "<DateTime '{datetime}'>"),
+            Type::Base64(bytes) => write_bytes(f, bytes),
+            Type::Struct { members } => {
+                f.write_char('{')?;
+                for (i, member) in members.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write_str(f, member.name())?;
+                    write!(f, ": {}", member.inner())?;
+                }
+                f.write_char('}')
+            },
+            Type::Array { data } => {
+                f.write_char('[')?;
+                write_list(f, data.inner())?;
+                f.write_char(']')
+            },
+            #[cfg(feature = "nil")]
+            Type::Nil => f.write_str("None"),
+        }
+    }
+}
+
+impl Display for MethodCall<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())?;
+        f.write_char('(')?;
+        write_list(f, self.values())?;
+        f.write_char(')')
+    }
+}
+
+impl Display for MethodResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+fn write_list<'a>(f: &mut Formatter<'_>, values: impl IntoIterator<Item = &'a Value>) -> fmt::Result {
+    for (i, value) in values.into_iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{value}")?;
+    }
+    Ok(())
+}
+
+// strings are single-quoted, with quotes, backslashes, and control characters escaped
+fn write_str(f: &mut Formatter<'_>, string: &str) -> fmt::Result {
+    f.write_char('\'')?;
+    for c in string.chars() {
+        match c {
+            '\'' => f.write_str("\\'")?,
+            '"' => f.write_char('"')?,
+            c => write!(f, "{}", c.escape_debug())?,
+        }
+    }
+    f.write_char('\'')
+}
+
+// bytes are rendered like Python bytes literals, with non-printable bytes hex-escaped
+fn write_bytes(f: &mut Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    f.write_str("b'")?;
+    for byte in bytes {
+        match byte {
+            b'"' => f.write_char('"')?,
+            byte => write!(f, "{}", byte.escape_ascii())?,
+        }
+    }
+    f.write_char('\'')
+}
@@ -0,0 +1,96 @@
+//! path queries for nested XML-RPC values
+
+use std::borrow::Cow;
+
+use super::types::{Type, Value};
+
+impl Value {
+    /// Look up a nested value with a path in JSON pointer syntax.
+    ///
+    /// The path consists of segments that are each prefixed with a `/` character. For `<struct>`
+    /// values, the segment is the name of a struct member, and for `<array>` values, the segment is
+    /// the index of an array item. The special characters `~` and `/` in member names need to be
+    /// escaped as `~0` and `~1`, respectively. The empty path refers to the value itself.
+    ///
+    /// This method returns `None` if the path is invalid, or if there is no value at the path.
+    ///
+    /// ```
+    /// use dxr::{value, Value};
+    ///
+    /// let value = value!({"builds": [{"id": 1, "owner_name": "alice"}]}).unwrap();
+    /// assert_eq!(
+    ///     value.pointer("/builds/0/owner_name"),
+    ///     Some(&Value::string(String::from("alice")))
+    /// );
+    /// ```
+    pub fn pointer(&self, path: &str) -> Option<&Value> {
+        let mut value = self;
+
+        for segment in segments(path)? {
+            value = match value.inner() {
+                Type::Struct { members } => members
+                    .iter()
+                    .find(|member| member.name() == segment)
+                    .map(|member| member.inner())?,
+                Type::Array { data } => data.inner().get(index(&segment)?)?,
+                _ => return None,
+            };
+        }
+
+        Some(value)
+    }
+
+    /// Look up a nested value with a path in JSON pointer syntax and return a mutable reference.
+    ///
+    /// The syntax of the path is the same as for [`Value::pointer`].
+    pub fn pointer_mut(&mut self, path: &str) -> Option<&mut Value> {
+        let mut value = self;
+
+        for segment in segments(path)? {
+            value = match value.inner_mut() {
+                Type::Struct { members } => members
+                    .iter_mut()
+                    .find(|member| member.name() == segment)
+                    .map(|member| member.inner_mut())?,
+                Type::Array { data } => data.inner_mut().get_mut(index(&segment)?)?,
+                _ => return None,
+            };
+        }
+
+        Some(value)
+    }
+}
+
+// split a path into unescaped segments (the empty path has no segments)
+fn segments(path: &str) -> Option<impl Iterator<Item = Cow<'_, str>>> {
+    let rest = if path.is_empty() {
+        None
+    } else {
+        Some(path.strip_prefix('/')?)
+    };
+
+    Some(rest.into_iter().flat_map(|rest| rest.split('/')).map(|segment| {
+        if segment.contains('~') {
+            Cow::Owned(segment.replace("~1", "/").replace("~0", "~"))
+        } else {
+            Cow::Borrowed(segment)
+        }
+    }))
+}
+
+// parse an array index (without leading zeros or signs)
+fn index(segment: &str) -> Option<usize> {
+    if (segment.len() > 1 && segment.starts_with('0')) || segment.starts_with('+') {
+        return None;
+    }
+    segment.parse().ok()
+}
+
+// escape a struct member name for use as a path segment
+pub(crate) fn escape(name: &str) -> Cow<'_, str> {
+    if name.contains(['~', '/']) {
+        Cow::Owned(name.replace('~', "~0").replace('/', "~1"))
+    } else {
+        Cow::Borrowed(name)
+    }
+}
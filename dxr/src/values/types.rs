@@ -4,6 +4,7 @@ use std::borrow::Cow;
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::DxrError;
 use crate::fault::Fault;
 use crate::values::DateTime;
 
@@ -40,6 +41,15 @@ impl Value {
         &self.value
     }
 
+    pub(crate) fn inner_mut(&mut self) -> &mut Type {
+        &mut self.value
+    }
+
+    /// name of the type of this value (i.e. `"i4"` or `"string"`)
+    pub fn type_name(&self) -> &'static str {
+        self.value.name()
+    }
+
     /// constructor for `<i4>` values (signed 32-bit integers)
     pub fn i4(value: i32) -> Value {
         Value::new(Type::Integer(value))
@@ -82,6 +92,18 @@ impl Value {
         Value::new(Type::Base64(value))
     }
 
+    /// Iterate over the names and values of the members of a `<struct>` value.
+    ///
+    /// This method returns an error if the value is not a `<struct>`. Unlike converting the value
+    /// into a [`HashMap`], this does not clone any member values, and duplicate member names are
+    /// preserved.
+    pub fn struct_members(&self) -> Result<impl Iterator<Item = (&str, &Value)>, DxrError> {
+        match &self.value {
+            Type::Struct { members } => Ok(members.iter().map(|member| (member.name(), member.inner()))),
+            t => Err(DxrError::wrong_type(t.name(), "struct")),
+        }
+    }
+
     pub(crate) fn structure(value: Struct) -> Value {
         Value::new(Type::Struct { members: value.members })
     }
@@ -219,6 +241,14 @@ impl Member {
     pub(crate) fn inner(&self) -> &Value {
         &self.value
     }
+
+    pub(crate) fn inner_mut(&mut self) -> &mut Value {
+        &mut self.value
+    }
+
+    pub(crate) fn parts_mut(&mut self) -> (&str, &mut Value) {
+        (self.name.name.as_str(), &mut self.value)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -247,6 +277,10 @@ impl ArrayData {
     pub(crate) fn inner(&self) -> &Vec<Value> {
         &self.values
     }
+
+    pub(crate) fn inner_mut(&mut self) -> &mut Vec<Value> {
+        &mut self.values
+    }
 }
 
 /// # XML-RPC method call type
@@ -290,6 +324,10 @@ impl<'a> MethodCall<'a> {
     pub fn params(self) -> Vec<Value> {
         self.params.params.into_iter().map(|param| param.value).collect()
     }
+
+    pub(crate) fn values(&self) -> impl Iterator<Item = &Value> {
+        self.params.params.iter().map(|param| &param.value)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -324,6 +362,10 @@ impl MethodResponse {
     pub fn inner(self) -> Value {
         self.params.params.params
     }
+
+    pub(crate) fn value(&self) -> &Value {
+        &self.params.params.params
+    }
 }
 
 /// # XML-RPC fault response type
@@ -344,8 +386,8 @@ pub struct FaultResponse {
 }
 
 impl FaultResponse {
-    pub(crate) fn members(&self) -> &[Member] {
-        &self.fault.value.value.members
+    pub(crate) fn into_value(self) -> Value {
+        Value::structure(self.fault.value.value)
     }
 }
 
@@ -354,10 +396,7 @@ impl From<Fault> for FaultResponse {
         FaultResponse {
             fault: FaultStruct {
                 value: FaultValue {
-                    value: Struct::new(vec![
-                        Member::new(String::from("faultCode"), Value::i4(fault.code())),
-                        Member::new(String::from("faultString"), Value::string(fault.string().to_owned())),
-                    ]),
+                    value: fault.to_struct(),
                 },
             },
         }
@@ -0,0 +1,207 @@
+//! traits for recursively visiting and transforming XML-RPC values
+
+use super::datetime::DateTime;
+use super::types::{Type, Value};
+
+/// Trait for recursively visiting XML-RPC values by reference
+///
+/// Every method has a default implementation, so implementations only need to override the
+/// methods for the values they are interested in. The default implementation of
+/// [`Visit::visit_value`] calls [`walk_value`], which dispatches to the method for the type of the
+/// value, and which visits all members of `<struct>` values and all items of `<array>` values.
+/// Implementations that override [`Visit::visit_value`] can call [`walk_value`] to continue the
+/// recursion.
+///
+/// ```
+/// use dxr::{value, Visit};
+///
+/// // count the number of strings in a value, including nested ones
+/// struct Strings(usize);
+///
+/// impl Visit for Strings {
+///     fn visit_string(&mut self, _string: &str) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let mut visitor = Strings(0);
+/// visitor.visit_value(&value!({"name": "foo", "tags": ["a", "b"], "id": 1}).unwrap());
+/// assert_eq!(visitor.0, 3);
+/// ```
+pub trait Visit {
+    /// Visit a value of any type.
+    fn visit_value(&mut self, value: &Value) {
+        walk_value(self, value);
+    }
+
+    /// Visit an `<i4>` value.
+    fn visit_i4(&mut self, _int: i32) {}
+
+    /// Visit an `<i8>` value.
+    #[cfg(feature = "i8")]
+    fn visit_i8(&mut self, _long: i64) {}
+
+    /// Visit a `<boolean>` value.
+    fn visit_boolean(&mut self, _boolean: bool) {}
+
+    /// Visit a `<string>` value.
+    fn visit_string(&mut self, _string: &str) {}
+
+    /// Visit a `<double>` value.
+    fn visit_double(&mut self, _double: f64) {}
+
+    /// Visit a `<dateTime.iso8601>` value.
+    fn visit_datetime(&mut self, _datetime: &DateTime) {}
+
+    /// Visit a `<base64>` value.
+    fn visit_base64(&mut self, _bytes: &[u8]) {}
+
+    /// Visit a member of a `<struct>` value.
+    fn visit_member(&mut self, _name: &str, value: &Value) {
+        self.visit_value(value);
+    }
+
+    /// Visit an item of an `<array>` value.
+    fn visit_item(&mut self, _index: usize, value: &Value) {
+        self.visit_value(value);
+    }
+
+    /// Visit a `<nil/>` value.
+    #[cfg(feature = "nil")]
+    fn visit_nil(&mut self) {}
+}
+
+/// Trait for recursively visiting XML-RPC values by mutable reference
+///
+/// This is the equivalent of [`Visit`] for transforming values in-place. Values can either be
+/// modified by overriding the method for a specific type, or replaced entirely (including with
+/// values of a different type) by overriding [`VisitMut::visit_value_mut`]. The default
+/// implementation of [`VisitMut::visit_value_mut`] calls [`walk_value_mut`].
+///
+/// ```
+/// use dxr::{value, Value, VisitMut};
+///
+/// // redact the values of all struct members named "password"
+/// struct Redact;
+///
+/// impl VisitMut for Redact {
+///     fn visit_member_mut(&mut self, name: &str, value: &mut Value) {
+///         if name == "password" {
+///             *value = Value::string(String::from("***"));
+///         } else {
+///             self.visit_value_mut(value);
+///         }
+///     }
+/// }
+///
+/// let mut value = value!({"users": [{"name": "alice", "password": "hunter2"}]}).unwrap();
+/// Redact.visit_value_mut(&mut value);
+/// assert_eq!(value, value!({"users": [{"name": "alice", "password": "***"}]}).unwrap());
+/// ```
+pub trait VisitMut {
+    /// Visit a value of any type.
+    fn visit_value_mut(&mut self, value: &mut Value) {
+        walk_value_mut(self, value);
+    }
+
+    /// Visit an `<i4>` value.
+    fn visit_i4_mut(&mut self, _int: &mut i32) {}
+
+    /// Visit an `<i8>` value.
+    #[cfg(feature = "i8")]
+    fn visit_i8_mut(&mut self, _long: &mut i64) {}
+
+    /// Visit a `<boolean>` value.
+    fn visit_boolean_mut(&mut self, _boolean: &mut bool) {}
+
+    /// Visit a `<string>` value.
+    fn visit_string_mut(&mut self, _string: &mut String) {}
+
+    /// Visit a `<double>` value.
+    fn visit_double_mut(&mut self, _double: &mut f64) {}
+
+    /// Visit a `<dateTime.iso8601>` value.
+    fn visit_datetime_mut(&mut self, _datetime: &mut DateTime) {}
+
+    /// Visit a `<base64>` value.
+    fn visit_base64_mut(&mut self, _bytes: &mut Vec<u8>) {}
+
+    /// Visit a member of a `<struct>` value.
+    fn visit_member_mut(&mut self, _name: &str, value: &mut Value) {
+        self.visit_value_mut(value);
+    }
+
+    /// Visit an item of an `<array>` value.
+    fn visit_item_mut(&mut self, _index: usize, value: &mut Value) {
+        self.visit_value_mut(value);
+    }
+
+    /// Visit a `<nil/>` value.
+    #[cfg(feature = "nil")]
+    fn visit_nil_mut(&mut self) {}
+}
+
+/// Dispatch a value to the [`Visit`] method for its type.
+///
+/// For `<struct>` values, this calls [`Visit::visit_member`] for every member, and for `<array>`
+/// values, this calls [`Visit::visit_item`] for every item.
+pub fn walk_value<V>(visitor: &mut V, value: &Value)
+where
+    V: Visit + ?Sized,
+{
+    match value.inner() {
+        Type::Integer(int) => visitor.visit_i4(*int),
+        #[cfg(feature = "i8")]
+        Type::Long(long) => visitor.visit_i8(*long),
+        Type::Boolean(boolean) => visitor.visit_boolean(*boolean),
+        Type::String(string) => visitor.visit_string(string),
+        Type::Double(double) => visitor.visit_double(*double),
+        Type::DateTime(datetime) => visitor.visit_datetime(datetime),
+        Type::Base64(bytes) => visitor.visit_base64(bytes),
+        Type::Struct { members } => {
+            for member in members {
+                visitor.visit_member(member.name(), member.inner());
+            }
+        },
+        Type::Array { data } => {
+            for (index, item) in data.inner().iter().enumerate() {
+                visitor.visit_item(index, item);
+            }
+        },
+        #[cfg(feature = "nil")]
+        Type::Nil => visitor.visit_nil(),
+    }
+}
+
+/// Dispatch a value to the [`VisitMut`] method for its type.
+///
+/// For `<struct>` values, this calls [`VisitMut::visit_member_mut`] for every member, and for
+/// `<array>` values, this calls [`VisitMut::visit_item_mut`] for every item.
+pub fn walk_value_mut<V>(visitor: &mut V, value: &mut Value)
+where
+    V: VisitMut + ?Sized,
+{
+    match value.inner_mut() {
+        Type::Integer(int) => visitor.visit_i4_mut(int),
+        #[cfg(feature = "i8")]
+        Type::Long(long) => visitor.visit_i8_mut(long),
+        Type::Boolean(boolean) => visitor.visit_boolean_mut(boolean),
+        Type::String(string) => visitor.visit_string_mut(string),
+        Type::Double(double) => visitor.visit_double_mut(double),
+        Type::DateTime(datetime) => visitor.visit_datetime_mut(datetime),
+        Type::Base64(bytes) => visitor.visit_base64_mut(bytes),
+        Type::Struct { members } => {
+            for member in members {
+                let (name, value) = member.parts_mut();
+                visitor.visit_member_mut(name, value);
+            }
+        },
+        Type::Array { data } => {
+            for (index, item) in data.inner_mut().iter_mut().enumerate() {
+                visitor.visit_item_mut(index, item);
+            }
+        },
+        #[cfg(feature = "nil")]
+        Type::Nil => visitor.visit_nil_mut(),
+    }
+}
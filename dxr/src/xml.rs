@@ -1,8 +1,75 @@
-use quick_xml::de::DeError;
+use std::borrow::Cow;
+use std::io::Read;
+
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
+use quick_xml::de::{DeError, Deserializer, EntityResolver, PredefinedEntityResolver};
+use quick_xml::events::BytesText;
 use quick_xml::se::{QuoteLevel, SeError, Serializer};
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::validation::{validate_strict, Lenient, Validation};
+
+/// Options for serializing and deserializing values as XML.
+///
+/// By default, values are serialized without indentation and without an XML declaration, which
+/// is the most compact representation. Enabling indentation can be useful for logging.
+///
+/// ```
+/// use dxr::{serialize_xml_with_options, MethodCall, Value, XmlOptions};
+///
+/// let call = MethodCall::new("hello", vec![Value::string(String::from("xmlrpc"))]);
+/// let options = XmlOptions::new().indent(' ', 2).declaration(true);
+/// let xml = serialize_xml_with_options(&call, &options).unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct XmlOptions {
+    indent: Option<(char, usize)>,
+    declaration: bool,
+    encoding: Option<&'static Encoding>,
+    validation: Validation,
+}
+
+impl XmlOptions {
+    /// Constructor for [`XmlOptions`] with default settings.
+    pub fn new() -> Self {
+        XmlOptions::default()
+    }
+
+    /// Method for enabling pretty-printing with the given indentation character and width.
+    pub fn indent(mut self, indent_char: char, indent_size: usize) -> Self {
+        self.indent = Some((indent_char, indent_size));
+        self
+    }
+
+    /// Method for enabling or disabling the `<?xml version="1.0"?>` declaration.
+    pub fn declaration(mut self, declaration: bool) -> Self {
+        self.declaration = declaration;
+        self
+    }
+
+    /// Method for setting the character encoding of XML output (UTF-8 by default).
+    ///
+    /// This setting only applies to [`serialize_xml_bytes`]. The encoding is declared in the XML
+    /// declaration, which is always included for encodings other than UTF-8. Characters that
+    /// cannot be represented in the target encoding are written as numeric character references
+    /// (i.e. `&#8364;`). UTF-16 output starts with a byte order mark.
+    pub fn encoding(mut self, encoding: &'static Encoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Method for setting the level of [`Validation`] (the default is [`Validation::Standard`]).
+    ///
+    /// This setting applies to [`deserialize_xml_with_options`], and with [`Validation::Strict`],
+    /// also to the names of method calls that are serialized.
+    pub fn validation(mut self, validation: Validation) -> Self {
+        self.validation = validation;
+        self
+    }
+}
+
 /// Custom function for serializing values as XML.
 ///
 /// This function uses a custom [`Serializer`] that expands empty XML elements
@@ -15,23 +82,269 @@ pub fn serialize_xml<T>(value: &T) -> Result<String, SeError>
 where
     T: Serialize,
 {
+    serialize_xml_with_options(value, &XmlOptions::default())
+}
+
+/// Custom function for serializing values as XML with custom [`XmlOptions`].
+///
+/// This works like [`serialize_xml`], but additionally supports pretty-printing the XML
+/// output and prepending an XML declaration.
+pub fn serialize_xml_with_options<T>(value: &T, options: &XmlOptions) -> Result<String, SeError>
+where
+    T: Serialize,
+{
+    let mut buf = String::new();
+
+    if options.declaration {
+        write_declaration(&mut buf, None, options);
+    }
+
+    write_xml(&mut buf, value, options)?;
+    validate_output(&buf, options)?;
+    Ok(buf)
+}
+
+/// Custom function for serializing values as XML in the character encoding that is specified in
+/// the [`XmlOptions`].
+///
+/// This works like [`serialize_xml_with_options`], but returns the encoded bytes of the XML
+/// document instead of a string.
+pub fn serialize_xml_bytes<T>(value: &T, options: &XmlOptions) -> Result<Vec<u8>, SeError>
+where
+    T: Serialize,
+{
+    let encoding = match options.encoding {
+        Some(encoding) if encoding == UTF_16LE || encoding == UTF_16BE => encoding,
+        Some(encoding) => encoding.output_encoding(),
+        None => UTF_8,
+    };
+
     let mut buf = String::new();
 
+    if encoding != UTF_8 {
+        write_declaration(&mut buf, Some(encoding.name()), options);
+    } else if options.declaration {
+        write_declaration(&mut buf, None, options);
+    }
+
+    write_xml(&mut buf, value, options)?;
+    validate_output(&buf, options)?;
+
+    let bytes = if encoding == UTF_16LE {
+        [0xFF, 0xFE]
+            .into_iter()
+            .chain(buf.encode_utf16().flat_map(u16::to_le_bytes))
+            .collect()
+    } else if encoding == UTF_16BE {
+        [0xFE, 0xFF]
+            .into_iter()
+            .chain(buf.encode_utf16().flat_map(u16::to_be_bytes))
+            .collect()
+    } else {
+        match encoding.encode(&buf).0 {
+            Cow::Borrowed(_) => buf.into_bytes(),
+            Cow::Owned(bytes) => bytes,
+        }
+    };
+
+    Ok(bytes)
+}
+
+fn validate_output(xml: &str, options: &XmlOptions) -> Result<(), SeError> {
+    match options.validation {
+        Validation::Strict => validate_strict(xml).map_err(SeError::Custom),
+        _ => Ok(()),
+    }
+}
+
+fn write_declaration(buf: &mut String, encoding: Option<&str>, options: &XmlOptions) {
+    match encoding {
+        Some(encoding) => buf.push_str(&format!(r#"<?xml version="1.0" encoding="{encoding}"?>"#)),
+        None => buf.push_str(r#"<?xml version="1.0"?>"#),
+    }
+    if options.indent.is_some() {
+        buf.push('\n');
+    }
+}
+
+fn write_xml<T>(buf: &mut String, value: &T, options: &XmlOptions) -> Result<(), SeError>
+where
+    T: Serialize,
+{
     // initialize custom serializer that expands empty elements
-    let mut serializer = Serializer::new(&mut buf);
+    let mut serializer = Serializer::new(buf);
     serializer.expand_empty_elements(true);
     serializer.set_quote_level(QuoteLevel::Full);
 
+    if let Some((indent_char, indent_size)) = options.indent {
+        serializer.indent(indent_char, indent_size);
+    }
+
     value.serialize(serializer)?;
-    Ok(buf)
+    Ok(())
 }
 
 /// Function for deserializing values from XML.
 ///
-/// This is a wrapper around [`quick_xml::de::from_str`].
+/// This is a wrapper around [`quick_xml::de::from_str`] with stricter handling of DTDs:
+///
+/// - Documents that contain a `<!DOCTYPE>` declaration are rejected, which rules out entity
+///   expansion attacks. XML-RPC does not use DTDs.
+/// - Only the predefined XML entities (`&lt;`, `&gt;`, `&amp;`, `&apos;`, and `&quot;`) and
+///   numeric character references (i.e. `&#233;`) are supported.
+/// - `<![CDATA[...]]>` sections are accepted as text content (i.e. for `<string>` values).
+/// - Comments and processing instructions are ignored.
 pub fn deserialize_xml<'de, T>(string: &'de str) -> Result<T, DeError>
 where
     T: Deserialize<'de>,
 {
-    quick_xml::de::from_str(string)
+    let mut deserializer = Deserializer::from_str_with_resolver(string, RejectDtd);
+    T::deserialize(&mut deserializer)
+}
+
+/// Function for deserializing values from XML with custom [`XmlOptions`].
+///
+/// This works like [`deserialize_xml`], but applies the configured level of [`Validation`].
+pub fn deserialize_xml_with_options<'de, T>(string: &'de str, options: &XmlOptions) -> Result<T, DeError>
+where
+    T: Deserialize<'de>,
+{
+    let mut deserializer = Deserializer::from_str_with_resolver(string, RejectDtd);
+
+    match options.validation {
+        Validation::Lenient => T::deserialize(Lenient::new(&mut deserializer)),
+        Validation::Standard => T::deserialize(&mut deserializer),
+        Validation::Strict => {
+            // syntax errors are reported by the deserializer before the contents are validated
+            let value = T::deserialize(&mut deserializer)?;
+            validate_strict(string).map_err(DeError::Custom)?;
+            Ok(value)
+        },
+    }
+}
+
+// entity resolver that rejects DTDs and only resolves predefined entities
+struct RejectDtd;
+
+#[derive(Debug, thiserror::Error)]
+#[error("DOCTYPE declarations are not supported")]
+struct DtdError;
+
+impl EntityResolver for RejectDtd {
+    type Error = DtdError;
+
+    fn capture(&mut self, _doctype: BytesText) -> Result<(), Self::Error> {
+        Err(DtdError)
+    }
+
+    fn resolve(&self, entity: &str) -> Option<&str> {
+        PredefinedEntityResolver.resolve(entity)
+    }
+}
+
+/// Error type for XML documents that cannot be decoded from bytes.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum DecodeError {
+    /// Error variant for documents that declare an encoding that is not supported.
+    #[error("Unsupported encoding: {}", .encoding)]
+    UnsupportedEncoding {
+        /// label of the declared encoding
+        encoding: String,
+    },
+    /// Error variant for documents that contain data that is not valid in their encoding.
+    #[error("Invalid {} data", .encoding)]
+    InvalidCharacter {
+        /// name of the encoding
+        encoding: &'static str,
+    },
+}
+
+impl From<DecodeError> for DeError {
+    fn from(error: DecodeError) -> Self {
+        DeError::Custom(error.to_string())
+    }
+}
+
+/// Function for decoding XML documents from bytes.
+///
+/// The character encoding is determined from the byte order mark (if present), or from the
+/// `encoding` attribute of the XML declaration (i.e. `<?xml version="1.0" encoding="ISO-8859-1"?>`).
+/// Documents without either are decoded as UTF-8, which is the default encoding for XML.
+///
+/// Note that the XML declaration is preserved in the returned string, even if it declares an
+/// encoding other than UTF-8. This does not affect [`deserialize_xml`].
+pub fn decode_xml(bytes: &[u8]) -> Result<Cow<'_, str>, DecodeError> {
+    let (encoding, bytes) = match Encoding::for_bom(bytes) {
+        Some((encoding, bom_length)) => (encoding, &bytes[bom_length..]),
+        None => match declared_encoding(bytes) {
+            Some(label) => match Encoding::for_label(label) {
+                // an ASCII-compatible document cannot actually be encoded as UTF-16
+                Some(encoding) if encoding == UTF_16LE || encoding == UTF_16BE => (UTF_8, bytes),
+                Some(encoding) => (encoding, bytes),
+                None => {
+                    return Err(DecodeError::UnsupportedEncoding {
+                        encoding: String::from_utf8_lossy(label).into_owned(),
+                    })
+                },
+            },
+            None => (UTF_8, bytes),
+        },
+    };
+
+    encoding
+        .decode_without_bom_handling_and_without_replacement(bytes)
+        .ok_or_else(|| DecodeError::InvalidCharacter {
+            encoding: encoding.name(),
+        })
+}
+
+// extract the value of the "encoding" attribute from the XML declaration
+fn declared_encoding(bytes: &[u8]) -> Option<&[u8]> {
+    let rest = bytes.strip_prefix(b"<?xml")?;
+    let declaration = &rest[..rest.windows(2).position(|window| window == b"?>")?];
+
+    let start = declaration.windows(8).position(|window| window == b"encoding")? + 8;
+    let rest = trim_start(&declaration[start..]);
+    let rest = trim_start(rest.strip_prefix(b"=")?);
+
+    let (quote, rest) = rest.split_first()?;
+    if *quote != b'"' && *quote != b'\'' {
+        return None;
+    }
+    let end = rest.iter().position(|byte| byte == quote)?;
+    Some(&rest[..end])
+}
+
+fn trim_start(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+/// Function for deserializing values from XML documents in any supported character encoding.
+///
+/// The bytes are decoded with [`decode_xml`] before they are deserialized.
+pub fn deserialize_xml_bytes<T>(bytes: &[u8]) -> Result<T, DeError>
+where
+    T: DeserializeOwned,
+{
+    deserialize_xml(&decode_xml(bytes)?)
+}
+
+/// Function for deserializing values from XML documents that are read from a [`Read`]er.
+///
+/// The reader is consumed until the end of input, and the contents are deserialized with
+/// [`deserialize_xml_bytes`].
+pub fn from_reader<R, T>(mut reader: R) -> Result<T, DeError>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .map_err(|error| DeError::from(quick_xml::Error::from(error)))?;
+    deserialize_xml_bytes(&bytes)
 }
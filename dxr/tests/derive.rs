@@ -10,6 +10,9 @@ fn try_build_pass() {
     t.pass("tests/trybuild/custom_result_type.rs");
     t.pass("tests/trybuild/ownership.rs");
     t.pass("tests/trybuild/collections.rs");
+    t.pass("tests/trybuild/params.rs");
+    t.pass("tests/trybuild/borrowed.rs");
+    t.pass("tests/trybuild/bounds.rs");
 }
 
 #[rustversion::stable]
@@ -20,6 +23,9 @@ fn try_build_fail() {
     t.compile_fail("tests/trybuild/toref.rs");
     t.compile_fail("tests/trybuild/tuple.rs");
     t.compile_fail("tests/trybuild/enum.rs");
+    t.compile_fail("tests/trybuild/params_order.rs");
+    t.compile_fail("tests/trybuild/variadic_value.rs");
+    t.compile_fail("tests/trybuild/fault_code.rs");
 }
 
 #[rustversion::nightly]
@@ -29,4 +35,7 @@ fn try_build_fail() {
     t.compile_fail("tests/trybuild/slice.rs");
     t.compile_fail("tests/trybuild/tuple.rs");
     t.compile_fail("tests/trybuild/enum.rs");
+    t.compile_fail("tests/trybuild/params_order.rs");
+    t.compile_fail("tests/trybuild/variadic_value.rs");
+    t.compile_fail("tests/trybuild/fault_code.rs");
 }
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use dxr::{TryBorrowFromValue, TryToValue, Value};
+
+#[derive(TryBorrowFromValue, TryToValue)]
+pub struct Borrowed<'a> {
+    string: &'a str,
+    bytes: &'a [u8],
+    value: &'a Value,
+    owned: String,
+    int: i32,
+    inner: Inner<'a>,
+}
+
+#[derive(TryBorrowFromValue, TryToValue)]
+pub struct Inner<'a> {
+    map: HashMap<&'a str, &'a str>,
+}
+
+#[derive(TryBorrowFromValue)]
+pub struct Generic<'a, T> {
+    string: &'a str,
+    item: T,
+}
+
+fn main() {
+    let value = Borrowed {
+        string: "hello",
+        bytes: b"world",
+        value: &Value::i4(42),
+        owned: String::from("owned"),
+        int: 42,
+        inner: Inner { map: HashMap::new() },
+    }
+    .try_to_value()
+    .unwrap();
+
+    let borrowed = Borrowed::try_borrow_from_value(&value).unwrap();
+    assert_eq!(borrowed.string, "hello");
+}
@@ -0,0 +1,52 @@
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use dxr::{DxrError, TryFromParams, TryFromValue, TryToParams, TryToValue, Value};
+
+pub trait Marker {}
+
+#[derive(TryFromValue, TryToValue)]
+pub struct Phantom<T: Marker> {
+    name: String,
+    marker: PhantomData<T>,
+}
+
+fn message_from_value(value: &Value) -> Result<Box<dyn Display>, DxrError> {
+    Ok(Box::new(String::try_from_value(value)?))
+}
+
+fn message_to_value(message: &Box<dyn Display>) -> Result<Value, DxrError> {
+    Ok(Value::string(message.to_string()))
+}
+
+#[derive(TryFromValue, TryToValue)]
+#[dxr(bound = "")]
+pub struct Custom<T> {
+    #[dxr(from_value = "message_from_value", to_value = "message_to_value")]
+    message: Box<dyn Display>,
+    marker: PhantomData<T>,
+}
+
+#[derive(TryFromValue, TryToValue)]
+#[dxr(bound = "T: TryFromValue + TryToValue + Clone")]
+pub struct Explicit<T> {
+    item: T,
+}
+
+#[derive(TryFromParams, TryToParams)]
+#[dxr(bound = "T: TryFromValue + TryToValue + Default")]
+pub struct Params<T> {
+    first: T,
+}
+
+struct NotAValue;
+
+fn main() {
+    let custom: Custom<NotAValue> = Custom {
+        message: Box::new("hello"),
+        marker: PhantomData,
+    };
+    let value = custom.try_to_value().unwrap();
+    let custom = Custom::<NotAValue>::try_from_value(&value).unwrap();
+    assert_eq!(custom.message.to_string(), "hello");
+}
@@ -0,0 +1,11 @@
+use dxr::IntoFault;
+
+#[derive(IntoFault)]
+enum Error {
+    #[fault(code = 1)]
+    NotFound,
+    #[fault(message = "Forbidden")]
+    Forbidden,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use dxr::{TryFromParams, TryToParams, Value, XmlRpcParams};
+
+#[derive(TryFromParams, TryToParams, XmlRpcParams)]
+pub struct Params {
+    name: String,
+    count: i32,
+    r#type: Option<String>,
+    #[dxr(default)]
+    flags: Vec<bool>,
+}
+
+#[derive(TryFromParams, TryToParams, XmlRpcParams)]
+pub struct Variadic<T> {
+    method: String,
+    #[dxr(variadic)]
+    args: Vec<T>,
+}
+
+#[derive(TryFromParams, TryToParams)]
+pub struct Everything {
+    value: Value,
+    option: Option<i64>,
+    #[dxr(variadic)]
+    rest: Vec<Value>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dxr::TryFromParams;
+
+#[derive(TryFromParams)]
+pub struct Params {
+    name: Option<String>,
+    count: i32,
+}
+
+fn main() {}
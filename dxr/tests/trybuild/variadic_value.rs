@@ -0,0 +1,10 @@
+use dxr::TryFromValue;
+
+#[derive(TryFromValue)]
+pub struct Values {
+    name: String,
+    #[dxr(variadic)]
+    rest: Vec<i32>,
+}
+
+fn main() {}
@@ -0,0 +1,185 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{timeout_at, Instant};
+
+use dxr::{DxrError, Fault, Value};
+
+use crate::{Client, ClientError};
+
+/// Options for automatically batching concurrent calls into "system.multicall" calls.
+///
+/// Calls that are issued within the batching window (starting with the first call of a batch) are
+/// sent as a single "system.multicall" call, unless the maximum number of calls per batch is
+/// reached earlier. The default is a window of 10 milliseconds with up to 50 calls per batch.
+///
+/// If the server does not support "system.multicall" calls (i.e. it returns a "method not found"
+/// fault), batching is disabled. If the server rejects a batch with another fault (e.g. because it
+/// limits the number of calls per "system.multicall" call), the maximum number of calls per batch
+/// is halved. In both cases, the calls of the rejected batch are sent individually.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use dxr_client::{BatchOptions, ClientBuilder};
+///
+/// let client = ClientBuilder::new("https://example.com/xmlrpc".parse().unwrap())
+///     .batching(BatchOptions::new().window(Duration::from_millis(5)).max_calls(100))
+///     .build();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BatchOptions {
+    window: Duration,
+    max_calls: usize,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        BatchOptions {
+            window: Duration::from_millis(10),
+            max_calls: 50,
+        }
+    }
+}
+
+impl BatchOptions {
+    /// Constructor for [`BatchOptions`] with default settings.
+    pub fn new() -> Self {
+        BatchOptions::default()
+    }
+
+    /// Method for setting the time window in which calls are collected into a batch.
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Method for setting the maximum number of calls per batch (at least one).
+    pub fn max_calls(mut self, max_calls: usize) -> Self {
+        self.max_calls = max_calls.max(1);
+        self
+    }
+}
+
+// method call that is waiting to be sent as part of a batch
+#[derive(Debug)]
+struct Pending {
+    method: String,
+    params: Vec<Value>,
+    reply: oneshot::Sender<Result<Value, ClientError>>,
+}
+
+// state of the batching mode of a client
+#[derive(Debug)]
+pub(crate) struct Batcher {
+    options: BatchOptions,
+    // the background task is only started with the first call (which runs inside the runtime)
+    sender: OnceLock<mpsc::UnboundedSender<Pending>>,
+}
+
+impl Batcher {
+    pub(crate) fn new(options: BatchOptions) -> Self {
+        Batcher {
+            options,
+            sender: OnceLock::new(),
+        }
+    }
+
+    pub(crate) async fn call(&self, client: &Client, method: &str, params: Vec<Value>) -> Result<Value, ClientError> {
+        let sender = self.sender.get_or_init(|| {
+            let (sender, receiver) = mpsc::unbounded_channel();
+            tokio::spawn(run(client.unbatched(), self.options, receiver));
+            sender
+        });
+
+        let (reply, result) = oneshot::channel();
+        let pending = Pending {
+            method: method.to_owned(),
+            params,
+            reply,
+        };
+
+        if sender.send(pending).is_err() {
+            return Err(cancelled());
+        }
+
+        result.await.unwrap_or_else(|_| Err(cancelled()))
+    }
+}
+
+fn cancelled() -> ClientError {
+    ClientError::from(DxrError::invalid_data(String::from("Batched call was cancelled")))
+}
+
+// collect pending calls into batches until the client is dropped
+async fn run(client: Client, options: BatchOptions, mut receiver: mpsc::UnboundedReceiver<Pending>) {
+    let client = Arc::new(client);
+    // the maximum number of calls per batch is reduced if the server rejects batches
+    let max_calls = Arc::new(AtomicUsize::new(options.max_calls));
+
+    while let Some(first) = receiver.recv().await {
+        let deadline = Instant::now() + options.window;
+        let mut batch = vec![first];
+
+        while batch.len() < max_calls.load(Ordering::Relaxed) {
+            match timeout_at(deadline, receiver.recv()).await {
+                Ok(Some(pending)) => batch.push(pending),
+                Ok(None) | Err(_) => break,
+            }
+        }
+
+        tokio::spawn(send(client.clone(), max_calls.clone(), batch));
+    }
+}
+
+async fn send(client: Arc<Client>, max_calls: Arc<AtomicUsize>, batch: Vec<Pending>) {
+    if batch.len() == 1 {
+        return send_individually(client, batch);
+    }
+
+    let calls = batch
+        .iter()
+        .map(|pending| (pending.method.clone(), pending.params.clone()))
+        .collect();
+
+    match client.multicall(calls).await {
+        Ok(results) => {
+            for (pending, result) in batch.into_iter().zip(results) {
+                let _ = pending.reply.send(result.map_err(ClientError::from));
+            }
+        },
+        // the server does not support "system.multicall" calls
+        Err(ClientError::Fault { fault }) if fault.code() == Fault::METHOD_NOT_FOUND => {
+            log::info!("Disabling batching of calls after system.multicall failed: {fault}");
+            max_calls.store(1, Ordering::Relaxed);
+            send_individually(client, batch);
+        },
+        // the server rejected the batch as a whole (e.g. because it contains too many calls),
+        // so none of the calls were processed and they can be sent again
+        Err(ClientError::Fault { fault }) => {
+            let limit = (batch.len() / 2).max(1);
+            log::info!("Limiting batches to {limit} calls after system.multicall failed: {fault}");
+            max_calls.fetch_min(limit, Ordering::Relaxed);
+            send_individually(client, batch);
+        },
+        // calls might have been processed by the server, so they cannot be sent again
+        Err(error) => {
+            let error = Arc::new(error);
+            for pending in batch {
+                let _ = pending.reply.send(Err(ClientError::Batch { error: error.clone() }));
+            }
+        },
+    }
+}
+
+fn send_individually(client: Arc<Client>, batch: Vec<Pending>) {
+    for pending in batch {
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client.call_inner(pending.method.into(), pending.params).await;
+            let _ = pending.reply.send(result);
+        });
+    }
+}
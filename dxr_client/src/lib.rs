@@ -10,6 +10,18 @@ mod reqwest_support;
 #[cfg(feature = "reqwest")]
 pub use reqwest_support::*;
 
+#[cfg(all(feature = "multicall", feature = "reqwest"))]
+mod multicall;
+#[cfg(all(feature = "multicall", feature = "reqwest"))]
+pub use multicall::*;
+
+#[cfg(feature = "batching")]
+mod batching;
+#[cfg(feature = "batching")]
+pub use batching::BatchOptions;
+#[cfg(feature = "batching")]
+pub(crate) use batching::Batcher;
+
 // re-export url::URL, as it is exposed in the the public API
 #[cfg(feature = "reqwest")]
 pub use url::Url;
@@ -0,0 +1,216 @@
+use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use dxr::{DxrError, Fault, TryFromValue, TryToParams, Value};
+
+use crate::{Client, ClientError};
+
+/// Options for splitting large numbers of method calls into multiple "system.multicall" calls.
+///
+/// By default, all method calls are sent as a single "system.multicall" call. With a chunk size,
+/// method calls are split into chunks of (at most) this size, which are sent sequentially, or with
+/// up to the configured number of concurrent requests.
+///
+/// ```
+/// use dxr_client::MulticallOptions;
+///
+/// let options = MulticallOptions::new()
+///     .chunk_size(100)
+///     .concurrency(4)
+///     .progress(|done, total| println!("{done}/{total} calls"));
+/// ```
+#[derive(Clone)]
+pub struct MulticallOptions {
+    pub(crate) chunk_size: Option<usize>,
+    pub(crate) concurrency: usize,
+    pub(crate) progress: Option<Arc<dyn Fn(usize, usize) + Send + Sync>>,
+}
+
+impl Default for MulticallOptions {
+    fn default() -> Self {
+        MulticallOptions {
+            chunk_size: None,
+            concurrency: 1,
+            progress: None,
+        }
+    }
+}
+
+impl MulticallOptions {
+    /// Constructor for [`MulticallOptions`] with default settings.
+    pub fn new() -> Self {
+        MulticallOptions::default()
+    }
+
+    /// Method for setting the maximum number of method calls per "system.multicall" call.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size.max(1));
+        self
+    }
+
+    /// Method for setting the maximum number of concurrent requests (the default is one, i.e.
+    /// chunks are sent sequentially).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Method for setting a callback that is called after every completed chunk.
+    ///
+    /// The arguments are the number of method calls with results so far and the total number of
+    /// method calls. Chunks are reported in order, even if they are sent concurrently.
+    pub fn progress(mut self, progress: impl Fn(usize, usize) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+}
+
+impl Debug for MulticallOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MulticallOptions")
+            .field("chunk_size", &self.chunk_size)
+            .field("concurrency", &self.concurrency)
+            .field("progress", &self.progress.as_ref().map(|_| "..."))
+            .finish()
+    }
+}
+
+/// Builder for "system.multicall" calls with heterogeneous parameter and return types.
+///
+/// Every call that is added to the builder returns a [`CallHandle`], which can be used to extract
+/// the typed result of the call from the [`MulticallResults`] after the multicall was sent.
+///
+/// ```no_run
+/// # async fn multicall(client: &dxr_client::Client) -> Result<(), dxr_client::ClientError> {
+/// let mut multicall = client.multicall_builder();
+/// let sum = multicall.add::<i32>("add", (1, 2));
+/// let greeting = multicall.add::<String>("hello", "xmlrpc");
+///
+/// let results = multicall.send().await?;
+/// let sum: i32 = results.get(sum)?;
+/// let greeting: String = results.get(greeting)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MulticallBuilder<'a> {
+    client: &'a Client,
+    calls: Vec<(String, Vec<Value>)>,
+    error: Option<DxrError>,
+}
+
+impl<'a> MulticallBuilder<'a> {
+    pub(crate) fn new(client: &'a Client) -> Self {
+        MulticallBuilder {
+            client,
+            calls: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Method for adding a method call with the expected return type `R`.
+    ///
+    /// If the parameters cannot be converted into XML-RPC values, the error is returned by
+    /// [`MulticallBuilder::send`].
+    pub fn add<R: TryFromValue>(&mut self, method: &str, params: impl TryToParams) -> CallHandle<R> {
+        match params.try_to_params() {
+            Ok(params) => self.calls.push((method.to_owned(), params)),
+            Err(error) => {
+                self.error.get_or_insert(error);
+                self.calls.push((method.to_owned(), Vec::new()));
+            },
+        }
+
+        CallHandle {
+            index: self.calls.len() - 1,
+            marker: PhantomData,
+        }
+    }
+
+    /// Number of method calls that were added to the builder.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Check whether no method calls were added to the builder.
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Asynchronous method for sending all method calls in a single "system.multicall" call.
+    ///
+    /// This returns an error if the number of results returned by the server does not match the
+    /// number of method calls.
+    pub async fn send(self) -> Result<MulticallResults, ClientError> {
+        self.send_with_options(&MulticallOptions::default()).await
+    }
+
+    /// Asynchronous method for sending all method calls with custom [`MulticallOptions`].
+    ///
+    /// This works like [`Client::multicall_with_options`], i.e. method calls can be split into
+    /// multiple "system.multicall" calls.
+    pub async fn send_with_options(self, options: &MulticallOptions) -> Result<MulticallResults, ClientError> {
+        if let Some(error) = self.error {
+            return Err(error.into());
+        }
+
+        let results = self.client.multicall_with_options(self.calls, options).await?;
+        Ok(MulticallResults { results })
+    }
+}
+
+/// Handle for the result of a method call that was added to a [`MulticallBuilder`].
+pub struct CallHandle<R> {
+    index: usize,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<R> CallHandle<R> {
+    /// Index of the method call in the multicall.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+// manual implementations without unnecessary trait bounds on the return type
+
+impl<R> Clone for CallHandle<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R> Copy for CallHandle<R> {}
+
+impl<R> Debug for CallHandle<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallHandle").field("index", &self.index).finish()
+    }
+}
+
+/// Results of the method calls that were sent with a [`MulticallBuilder`].
+#[derive(Clone, Debug)]
+pub struct MulticallResults {
+    results: Vec<Result<Value, Fault>>,
+}
+
+impl MulticallResults {
+    /// Method for extracting the typed result of a method call.
+    ///
+    /// Faults that were returned for this method call are converted into [`ClientError::Fault`]
+    /// errors, and return values that cannot be converted into the expected type result in
+    /// [`ClientError::RPC`] errors.
+    pub fn get<R: TryFromValue>(&self, handle: CallHandle<R>) -> Result<R, ClientError> {
+        match self.results.get(handle.index) {
+            Some(Ok(value)) => Ok(R::try_from_value(value)?),
+            Some(Err(fault)) => Err(fault.clone().into()),
+            None => Err(DxrError::invalid_data(format!("Invalid multicall result index: {}", handle.index)).into()),
+        }
+    }
+
+    /// Method for extracting the untyped results of all method calls.
+    pub fn into_inner(self) -> Vec<Result<Value, Fault>> {
+        self.results
+    }
+}
@@ -1,15 +1,23 @@
 use std::borrow::Cow;
-#[cfg(feature = "multicall")]
-use std::collections::HashMap;
+use std::sync::Arc;
 
+#[cfg(feature = "multicall")]
+use futures_util::stream::{self, StreamExt};
 use http::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT};
 use thiserror::Error;
 use url::Url;
 
 use dxr::Value;
-use dxr::{DxrError, Fault, FaultResponse, MethodCall, MethodResponse, TryFromValue, TryToParams};
+use dxr::{
+    Capabilities, DxrError, Fault, FaultResponse, MethodCall, MethodResponse, TryFromFault, TryFromValue, TryToParams,
+    Validation, XmlOptions,
+};
 
 use crate::DEFAULT_USER_AGENT;
+#[cfg(feature = "batching")]
+use crate::{BatchOptions, Batcher};
+#[cfg(feature = "multicall")]
+use crate::{MulticallBuilder, MulticallOptions};
 
 /// Error type for XML-RPC clients based on [`reqwest`].
 #[derive(Debug, Error)]
@@ -35,6 +43,31 @@ pub enum ClientError {
         #[from]
         error: reqwest::Error,
     },
+    /// Error variant for batched calls that failed because the "system.multicall" call for their
+    /// batch failed.
+    ///
+    /// This variant is only returned by clients with batching enabled (which requires the
+    /// `batching` feature), but it is always available, so enabling the feature does not change
+    /// this type.
+    #[error("Batched call failed: {}", error)]
+    Batch {
+        /// Error of the "system.multicall" call (which is shared by all calls in the batch).
+        error: Arc<ClientError>,
+    },
+}
+
+impl ClientError {
+    /// Convert a server fault into a typed error.
+    ///
+    /// This returns `Ok(None)` if this error is not a [`ClientError::Fault`], or if the fault has
+    /// a fault code that is unknown to the target type. Faults with a known fault code that cannot
+    /// be converted (e.g. because of missing or invalid struct members) result in an error.
+    pub fn fault_as<E: TryFromFault>(&self) -> Result<Option<E>, DxrError> {
+        match self {
+            ClientError::Fault { fault } => E::try_from_fault(fault),
+            _ => Ok(None),
+        }
+    }
 }
 
 #[allow(unused)]
@@ -60,6 +93,9 @@ pub struct ClientBuilder {
     user_agent: Option<&'static str>,
     username: Option<String>,
     password: Option<String>,
+    validation: Validation,
+    #[cfg(feature = "batching")]
+    batching: Option<BatchOptions>,
 }
 
 impl ClientBuilder {
@@ -76,6 +112,9 @@ impl ClientBuilder {
             user_agent: None,
             username: None,
             password: None,
+            validation: Validation::default(),
+            #[cfg(feature = "batching")]
+            batching: None,
         }
     }
 
@@ -92,6 +131,35 @@ impl ClientBuilder {
         self
     }
 
+    /// Method for setting the level of [`Validation`] for requests and responses.
+    ///
+    /// [`Validation::Lenient`] can be useful for talking to servers that do not conform to the
+    /// XML-RPC specification, and [`Validation::Strict`] for testing servers that should.
+    pub fn validation(mut self, validation: Validation) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    /// Method for enabling automatic batching of concurrent calls into "system.multicall" calls.
+    ///
+    /// With batching enabled, calls made with [`Client::call`] that are issued concurrently
+    /// (within the time window that is configured with [`BatchOptions`]) are sent as a single
+    /// "system.multicall" call, and the results are returned to the individual calls. If the
+    /// server returns a fault for the "system.multicall" call itself, the calls of the batch are
+    /// sent individually, and batching is disabled or later batches are made smaller (see
+    /// [`BatchOptions`] for details).
+    ///
+    /// If the "system.multicall" call fails with an error other than a fault, this error is
+    /// returned (as a [`ClientError::Batch`] error) for all calls in the batch, since they might
+    /// have been processed by the server already.
+    ///
+    /// Batched calls need to be made from within a `tokio` runtime.
+    #[cfg(feature = "batching")]
+    pub fn batching(mut self, options: BatchOptions) -> Self {
+        self.batching = Some(options);
+        self
+    }
+
     /// Method for providing additional custom HTTP headers.
     ///
     /// Using [`HeaderName`] constants for the header name is recommended. The [`HeaderValue`]
@@ -121,6 +189,9 @@ impl ClientBuilder {
             client,
             username: builder.username,
             password: builder.password,
+            validation: builder.validation,
+            #[cfg(feature = "batching")]
+            batching: builder.batching.map(Batcher::new),
         }
     }
 }
@@ -135,6 +206,9 @@ pub struct Client {
     client: reqwest::Client,
     username: Option<String>,
     password: Option<String>,
+    validation: Validation,
+    #[cfg(feature = "batching")]
+    batching: Option<Batcher>,
 }
 
 impl Client {
@@ -145,6 +219,9 @@ impl Client {
             client,
             username: None,
             password: None,
+            validation: Validation::default(),
+            #[cfg(feature = "batching")]
+            batching: None,
         }
     }
 
@@ -160,6 +237,9 @@ impl Client {
             client,
             username,
             password,
+            validation: Validation::default(),
+            #[cfg(feature = "batching")]
+            batching: None,
         }
     }
 
@@ -169,16 +249,47 @@ impl Client {
     /// Invalid XML-RPC responses or faults will result in an appropriate [`DxrError`].
     pub async fn call<P: TryToParams, R: TryFromValue>(&self, method: &str, args: P) -> Result<R, ClientError> {
         let params = args.try_to_params()?;
+
+        #[cfg(feature = "batching")]
+        let result = match &self.batching {
+            Some(batcher) => batcher.call(self, method, params).await?,
+            None => self.call_inner(Cow::Borrowed(method), params).await?,
+        };
+        #[cfg(not(feature = "batching"))]
         let result = self.call_inner(Cow::Borrowed(method), params).await?;
 
         // extract return value
         Ok(R::try_from_value(&result)?)
     }
 
-    async fn call_inner(&self, method: Cow<'_, str>, params: Vec<Value>) -> Result<Value, ClientError> {
+    /// Asynchronous method for querying the capabilities of the server with the
+    /// `system.getCapabilities` method.
+    ///
+    /// This can be used to check whether the server supports extensions like `<nil/>` values or
+    /// "system.multicall" calls before using them. Servers that do not implement this method
+    /// usually return a fault.
+    pub async fn capabilities(&self) -> Result<Capabilities, ClientError> {
+        self.call("system.getCapabilities", ()).await
+    }
+
+    // copy of the client that sends all calls directly
+    #[cfg(feature = "batching")]
+    pub(crate) fn unbatched(&self) -> Client {
+        Client {
+            url: self.url.clone(),
+            client: self.client.clone(),
+            username: self.username.clone(),
+            password: self.password.clone(),
+            validation: self.validation,
+            batching: None,
+        }
+    }
+
+    pub(crate) async fn call_inner(&self, method: Cow<'_, str>, params: Vec<Value>) -> Result<Value, ClientError> {
         // serialize XML-RPC method call
         let request = MethodCall::new(method, params);
-        let body = request_to_body(&request)?;
+        log::trace!("Calling XML-RPC method: {}", request.name());
+        let body = request_to_body(&request, self.validation)?;
 
         // construct request and send to server
         let request = {
@@ -192,49 +303,89 @@ impl Client {
         let response = self.client.execute(request).await?;
 
         // deserialize XML-RPC method response
-        let contents = response.text().await?;
-        let result = response_to_result(&contents)?;
+        let contents = response.bytes().await?;
+        let contents = dxr::decode_xml(&contents).map_err(|error| DxrError::invalid_data(error.to_string()))?;
+        let result = response_to_result(&contents, self.validation)?;
 
         Ok(result.inner())
     }
 
     /// Asynchronous method for handling "system.multicall" calls.
     ///
-    /// *Note*: This method does not check if the number of method calls matches the number of
-    /// returned results.
+    /// This returns an error if the number of results returned by the server does not match the
+    /// number of method calls. For method calls with different parameter or return types, use
+    /// [`Client::multicall_builder`] instead.
     #[cfg(feature = "multicall")]
     pub async fn multicall<P: TryToParams>(
         &self,
         calls: Vec<(String, P)>,
     ) -> Result<Vec<Result<Value, Fault>>, ClientError> {
+        let count = calls.len();
         let calls = dxr::into_multicall_params(calls)?;
+        self.multicall_inner(calls, count).await
+    }
+
+    /// Asynchronous method for handling "system.multicall" calls with custom [`MulticallOptions`].
+    ///
+    /// The method calls are split into chunks (if a chunk size is set), which are sent as separate
+    /// "system.multicall" calls, either sequentially or with bounded concurrency. The results are
+    /// returned in the same order as the method calls. If any chunk fails, this method returns the
+    /// error and the results of all other chunks are discarded.
+    #[cfg(feature = "multicall")]
+    pub async fn multicall_with_options<P: TryToParams>(
+        &self,
+        calls: Vec<(String, P)>,
+        options: &MulticallOptions,
+    ) -> Result<Vec<Result<Value, Fault>>, ClientError> {
+        let total = calls.len();
+        let chunk_size = options.chunk_size.unwrap_or(total).max(1);
+
+        let mut calls = calls.into_iter();
+        let mut chunks = Vec::new();
+        loop {
+            let chunk: Vec<(String, P)> = calls.by_ref().take(chunk_size).collect();
+            if chunk.is_empty() {
+                break;
+            }
+            chunks.push((chunk.len(), dxr::into_multicall_params(chunk)?));
+        }
+
+        let mut responses = stream::iter(chunks)
+            .map(|(count, calls)| self.multicall_inner(calls, count))
+            .buffered(options.concurrency);
+
+        let mut results = Vec::with_capacity(total);
+        while let Some(response) = responses.next().await {
+            results.extend(response?);
+            if let Some(progress) = &options.progress {
+                progress(results.len(), total);
+            }
+        }
+
+        Ok(results)
+    }
+
+    #[cfg(feature = "multicall")]
+    async fn multicall_inner(&self, calls: Value, count: usize) -> Result<Vec<Result<Value, Fault>>, ClientError> {
         let response: Vec<Value> =
             TryFromValue::try_from_value(&self.call_inner(Cow::Borrowed("system.multicall"), vec![calls]).await?)?;
 
+        if response.len() != count {
+            return Err(ClientError::rpc(DxrError::invalid_data(format!(
+                "Expected {count} multicall results, but received {}",
+                response.len()
+            ))));
+        }
+
         let mut results = Vec::new();
         for result in response {
             // return values for successful calls are arrays that contain a single value
             if let Ok([value]) = <[Value; 1]>::try_from_value(&result) {
                 results.push(Ok(value));
             }
-            // return values for failed calls are structs with two members
-            else if let Ok(mut value) = <HashMap<String, Value>>::try_from_value(&result) {
-                let code = match value.remove("faultCode") {
-                    Some(code) => code,
-                    None => return Err(DxrError::missing_field("Fault", "faultCode").into()),
-                };
-
-                let string = match value.remove("faultString") {
-                    Some(string) => string,
-                    None => return Err(DxrError::missing_field("Fault", "faultString").into()),
-                };
-
-                // The value might still contain other struct fields:
-                // Rather than return an error because they are unexpected, they are ignored,
-                // since the required "faultCode" and "faultString" members were present.
-
-                let fault = Fault::new(i32::try_from_value(&code)?, String::try_from_value(&string)?);
-                results.push(Err(fault));
+            // return values for failed calls are structs with (at least) two members
+            else if result.struct_members().is_ok() {
+                results.push(Err(Fault::try_from_value(&result)?));
             }
             // return value does not match either expected format
             else {
@@ -246,12 +397,20 @@ impl Client {
 
         Ok(results)
     }
+
+    /// Method for constructing a [`MulticallBuilder`] for "system.multicall" calls with
+    /// heterogeneous parameter and return types.
+    #[cfg(feature = "multicall")]
+    pub fn multicall_builder(&self) -> MulticallBuilder<'_> {
+        MulticallBuilder::new(self)
+    }
 }
 
-fn request_to_body(call: &MethodCall) -> Result<String, DxrError> {
+fn request_to_body(call: &MethodCall, validation: Validation) -> Result<String, DxrError> {
+    let options = XmlOptions::new().validation(validation);
     let body = [
         r#"<?xml version="1.0"?>"#,
-        dxr::serialize_xml(&call)
+        dxr::serialize_xml_with_options(&call, &options)
             .map_err(|error| DxrError::invalid_data(error.to_string()))?
             .as_str(),
         "",
@@ -261,11 +420,13 @@ fn request_to_body(call: &MethodCall) -> Result<String, DxrError> {
     Ok(body)
 }
 
-fn response_to_result(contents: &str) -> Result<MethodResponse, ClientError> {
+fn response_to_result(contents: &str, validation: Validation) -> Result<MethodResponse, ClientError> {
+    let options = XmlOptions::new().validation(validation);
+
     // need to check for FaultResponse first:
     // - a missing <params> tag is ambiguous (can be either an empty response, or a fault response)
     // - a present <fault> tag is unambiguous
-    let error2 = match dxr::deserialize_xml(contents) {
+    let error2 = match dxr::deserialize_xml_with_options(contents, &options) {
         Ok(fault) => {
             let response: FaultResponse = fault;
             return match Fault::try_from(response) {
@@ -278,7 +439,7 @@ fn response_to_result(contents: &str) -> Result<MethodResponse, ClientError> {
         Err(error) => error.to_string(),
     };
 
-    let error1 = match dxr::deserialize_xml(contents) {
+    let error1 = match dxr::deserialize_xml_with_options(contents, &options) {
         Ok(response) => return Ok(response),
         Err(error) => error.to_string(),
     };
@@ -0,0 +1,144 @@
+//! parsing of `#[dxr(...)]` attributes on structs and struct fields
+
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_quote, Attribute, Field, GenericArgument, GenericParam, Generics, LitStr, Path, PathArguments, Token, Type,
+    TypeParamBound, WherePredicate,
+};
+
+/// settings that were specified with `#[dxr(...)]` attributes on a struct
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    /// custom trait bounds that replace the inferred bounds on type parameters
+    pub(crate) bound: Option<Punctuated<WherePredicate, Token![,]>>,
+}
+
+impl ContainerAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<ContainerAttrs> {
+        let mut container = ContainerAttrs::default();
+
+        for attr in attrs {
+            if !attr.path().is_ident("dxr") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bound") {
+                    let bound = meta.value()?.parse::<LitStr>()?;
+                    container.bound = Some(bound.parse_with(Punctuated::parse_terminated)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported dxr attribute"))
+                }
+            })?;
+        }
+
+        Ok(container)
+    }
+
+    /// Add trait bounds to the generics of the derived implementation.
+    ///
+    /// Unless custom bounds were specified, the given bound is added to all type parameters.
+    pub(crate) fn add_bounds(&self, generics: &mut Generics, inferred: TypeParamBound) {
+        match &self.bound {
+            Some(bound) => generics.make_where_clause().predicates.extend(bound.iter().cloned()),
+            None => {
+                for param in &mut generics.params {
+                    if let GenericParam::Type(ref mut type_param) = *param {
+                        type_param.bounds.push(inferred.clone());
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// settings that were specified with `#[dxr(...)]` attributes on a struct field
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    /// fall back to `Default::default()` if the value is missing
+    pub(crate) default: bool,
+    /// collect all remaining method call parameters into this field
+    pub(crate) variadic: bool,
+    /// custom function for converting the field into a value
+    pub(crate) to_value: Option<Path>,
+    /// custom function for converting a value into the field
+    pub(crate) from_value: Option<Path>,
+}
+
+impl FieldAttrs {
+    /// Parse the `#[dxr(...)]` attributes of a struct field.
+    ///
+    /// The `params` argument specifies whether the derived trait converts method call parameter
+    /// lists, since some attributes (like `variadic`) are only supported for parameter lists.
+    pub(crate) fn parse(field: &Field, params: bool) -> syn::Result<FieldAttrs> {
+        let mut attrs = FieldAttrs::default();
+
+        for attr in &field.attrs {
+            if !attr.path().is_ident("dxr") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    attrs.default = true;
+                    Ok(())
+                } else if meta.path.is_ident("variadic") {
+                    if !params {
+                        return Err(
+                            meta.error("variadic fields are only supported when deriving TryFromParams or TryToParams")
+                        );
+                    }
+                    attrs.variadic = true;
+                    Ok(())
+                } else if meta.path.is_ident("with") {
+                    let module: Path = meta.value()?.parse::<LitStr>()?.parse()?;
+                    attrs.to_value = Some(parse_quote!(#module::to_value));
+                    attrs.from_value = Some(parse_quote!(#module::from_value));
+                    Ok(())
+                } else if meta.path.is_ident("to_value") {
+                    attrs.to_value = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("from_value") {
+                    attrs.from_value = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported dxr attribute"))
+                }
+            })?;
+        }
+
+        if attrs.variadic && (attrs.to_value.is_some() || attrs.from_value.is_some()) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "custom conversion functions are not supported for variadic fields",
+            ));
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Check whether a type is (syntactically) a `PhantomData<T>`.
+pub(crate) fn is_phantom_data(ty: &Type) -> bool {
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            return segment.ident == "PhantomData";
+        }
+    }
+    false
+}
+
+/// Check whether a type is (syntactically) an `Option<T>`.
+pub(crate) fn is_option(ty: &Type) -> bool {
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            if segment.ident == "Option" {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    return matches!(args.args.first(), Some(GenericArgument::Type(_))) && args.args.len() == 1;
+                }
+            }
+        }
+    }
+    false
+}
@@ -0,0 +1,267 @@
+//! implementations of the derive macros for `IntoFault` and `TryFromFault`
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::{format_ident, quote, quote_spanned};
+
+use syn::{Attribute, Data, DataEnum, DeriveInput, Expr, Field, Fields, LitStr, Variant};
+
+use crate::use_dxr;
+
+/// settings that were specified with `#[fault(...)]` attributes on an enum variant
+struct VariantAttrs {
+    /// fault code (any constant expression of type `i32`)
+    code: Expr,
+    /// format string for the fault message
+    message: Option<LitStr>,
+}
+
+impl VariantAttrs {
+    fn parse(variant: &Variant) -> syn::Result<VariantAttrs> {
+        let mut code = None;
+        let mut message = None;
+
+        for attr in fault_attrs(&variant.attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("code") {
+                    code = Some(meta.value()?.parse::<Expr>()?);
+                    Ok(())
+                } else if meta.path.is_ident("message") {
+                    message = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported fault attribute"))
+                }
+            })?;
+        }
+
+        match code {
+            Some(code) => Ok(VariantAttrs { code, message }),
+            None => Err(syn::Error::new_spanned(
+                &variant.ident,
+                "missing #[fault(code = ...)] attribute",
+            )),
+        }
+    }
+}
+
+/// ways in which enum variant fields are mapped onto faults
+enum FieldKind {
+    /// field is not included in the fault (and set to its default value when converting back)
+    Skipped,
+    /// field is included as an additional struct member of the fault
+    Member(String),
+    /// field contains the fault message (when converting back)
+    String,
+}
+
+impl FieldKind {
+    fn parse(field: &Field) -> syn::Result<FieldKind> {
+        let mut kind = FieldKind::Skipped;
+
+        for attr in fault_attrs(&field.attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("member") {
+                    let ident = match &field.ident {
+                        Some(ident) => ident.to_string(),
+                        None => return Err(meta.error("only named fields can be fault members")),
+                    };
+                    let name = match ident.strip_prefix("r#") {
+                        Some(s) => s.to_owned(),
+                        None => ident,
+                    };
+                    kind = FieldKind::Member(name);
+                    Ok(())
+                } else if meta.path.is_ident("string") {
+                    kind = FieldKind::String;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported fault attribute"))
+                }
+            })?;
+        }
+
+        Ok(kind)
+    }
+}
+
+fn fault_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("fault"))
+}
+
+fn variants<'a>(input: &'a DeriveInput, derive: &str) -> Result<&'a DataEnum, TokenStream2> {
+    let name = &input.ident;
+
+    match &input.data {
+        Data::Enum(data) => Ok(data),
+        Data::Struct(_) | Data::Union(_) => {
+            let message = format!("Deriving {derive} for structs and unions is not supported.");
+            Err(quote_spanned! { name.span() => compile_error!(#message); })
+        },
+    }
+}
+
+/// Replace positional arguments (i.e. `{0}`) with the names of the bindings of tuple fields.
+fn message_format(message: &LitStr) -> LitStr {
+    let value = message.value();
+    let mut format = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        format.push(c);
+        if c == '{' {
+            match chars.peek() {
+                Some('{') => format.push(chars.next().expect("Failed to get next character.")),
+                Some(next) if next.is_ascii_digit() => format.push('_'),
+                _ => {},
+            }
+        }
+    }
+
+    LitStr::new(&format, message.span())
+}
+
+pub(crate) fn into_fault(input: DeriveInput) -> TokenStream2 {
+    let name = &input.ident;
+    let dxr = use_dxr();
+
+    let data = match variants(&input, "IntoFault") {
+        Ok(data) => data,
+        Err(error) => return error,
+    };
+
+    let mut arms = Vec::new();
+
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let VariantAttrs { code, message } = match VariantAttrs::parse(variant) {
+            Ok(attrs) => attrs,
+            Err(error) => return error.to_compile_error(),
+        };
+
+        let mut bindings = Vec::new();
+        let mut members = Vec::new();
+
+        for (index, field) in variant.fields.iter().enumerate() {
+            let binding = match &field.ident {
+                Some(ident) => ident.clone(),
+                None => format_ident!("_{}", index),
+            };
+
+            match FieldKind::parse(field) {
+                // members that cannot be converted contain the conversion error instead
+                Ok(FieldKind::Member(member)) => members.push(quote! {
+                    let value = #dxr::TryToValue::try_to_value(#binding)
+                        .unwrap_or_else(|error| #dxr::Value::string(::std::string::ToString::to_string(&error)));
+                    fault = fault.with_member(::std::string::String::from(#member), value);
+                }),
+                Ok(_) => {},
+                Err(error) => return error.to_compile_error(),
+            }
+
+            bindings.push(binding);
+        }
+
+        let pattern = match &variant.fields {
+            Fields::Named(_) => quote! { #name::#ident { #(#bindings),* } },
+            Fields::Unnamed(_) => quote! { #name::#ident ( #(#bindings),* ) },
+            Fields::Unit => quote! { #name::#ident },
+        };
+
+        let string = match message {
+            Some(message) => {
+                let format = message_format(&message);
+                quote! { ::std::format!(#format) }
+            },
+            None => quote! { ::std::string::ToString::to_string(&self) },
+        };
+
+        arms.push(quote! {
+            #pattern => {
+                let mut fault = #dxr::Fault::new(#code, #string);
+                #(#members)*
+                fault
+            },
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #dxr::IntoFault for #name #ty_generics #where_clause {
+            #[allow(unused_variables, unused_mut)]
+            fn into_fault(self) -> #dxr::Fault {
+                match &self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#name #ty_generics> for #dxr::Fault #where_clause {
+            fn from(error: #name #ty_generics) -> #dxr::Fault {
+                #dxr::IntoFault::into_fault(error)
+            }
+        }
+    }
+}
+
+pub(crate) fn try_from_fault(input: DeriveInput) -> TokenStream2 {
+    let name = &input.ident;
+    let dxr = use_dxr();
+
+    let data = match variants(&input, "TryFromFault") {
+        Ok(data) => data,
+        Err(error) => return error,
+    };
+
+    let mut checks = Vec::new();
+
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let VariantAttrs { code, .. } = match VariantAttrs::parse(variant) {
+            Ok(attrs) => attrs,
+            Err(error) => return error.to_compile_error(),
+        };
+
+        let mut values = Vec::new();
+
+        for field in &variant.fields {
+            let value = match FieldKind::parse(field) {
+                Ok(FieldKind::Skipped) => quote! { ::std::default::Default::default() },
+                Ok(FieldKind::Member(member)) => quote! { fault.member(#member)? },
+                Ok(FieldKind::String) => quote! { ::std::convert::From::from(fault.string()) },
+                Err(error) => return error.to_compile_error(),
+            };
+
+            values.push(match &field.ident {
+                Some(field_ident) => quote! { #field_ident: #value },
+                None => value,
+            });
+        }
+
+        let constructor = match &variant.fields {
+            Fields::Named(_) => quote! { #name::#ident { #(#values),* } },
+            Fields::Unnamed(_) => quote! { #name::#ident ( #(#values),* ) },
+            Fields::Unit => quote! { #name::#ident },
+        };
+
+        checks.push(quote! {
+            if fault.code() == #code {
+                return ::std::result::Result::Ok(::std::option::Option::Some(#constructor));
+            }
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #dxr::TryFromFault for #name #ty_generics #where_clause {
+            fn try_from_fault(
+                fault: &#dxr::Fault,
+            ) -> ::std::result::Result<::std::option::Option<Self>, #dxr::DxrError> {
+                #(#checks)*
+                ::std::result::Result::Ok(::std::option::Option::None)
+            }
+        }
+    }
+}
@@ -14,10 +14,14 @@ use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use proc_macro_crate::{crate_name, FoundCrate};
 
-use quote::{quote, quote_spanned, ToTokens};
+use quote::quote;
 
-use syn::spanned::Spanned;
-use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericParam, Ident, Type};
+use syn::{parse_macro_input, DeriveInput, Ident};
+
+mod attrs;
+mod fault;
+mod params;
+mod value;
 
 fn use_dxr() -> TokenStream2 {
     let krate = crate_name("dxr").ok().unwrap_or(FoundCrate::Itself);
@@ -41,115 +45,29 @@ fn use_dxr() -> TokenStream2 {
 /// - non-fixed-size arrays `[T]` (i.e. the struct does not implement `Sized`): use a `Vec<T>` or a
 ///   fixed-size array `[T; N]` instead
 /// - borrowed values `&T` (i.e. borrowed value does not live long enough): use an owned type `T` or
-///   a `Cow<T>` instead
-#[proc_macro_derive(TryFromValue)]
+///   a `Cow<T>` instead, or derive `TryBorrowFromValue`
+///
+/// The conversion of individual fields can be customized with attributes:
+///
+/// - `#[dxr(from_value = "path")]`: call the function at `path` (with signature
+///   `fn(&Value) -> Result<T, DxrError>`) instead of `<T as TryFromValue>::try_from_value`
+/// - `#[dxr(with = "module")]`: shorthand for `from_value = "module::from_value"` (and
+///   `to_value = "module::to_value"` when deriving `TryToValue`)
+/// - `#[dxr(default)]`: use `Default::default()` if the struct member is missing
+///
+/// Missing struct members are otherwise only allowed for types that provide a fallback value with
+/// `TryFromValue::try_from_missing` (i.e. fields of type `Option<T>` are set to `None`, and
+/// fields of type `Defaulted<T>` are set to their default value).
+///
+/// The `#[dxr(variadic)]` attribute is only supported for method call parameters (i.e. when
+/// deriving `TryFromParams`), and results in a compilation error.
+///
+/// Fields of type `PhantomData<T>` are skipped. Trait bounds on type parameters are inferred, but
+/// they can be overridden with a `#[dxr(bound = "T: Trait")]` attribute on the struct.
+#[proc_macro_derive(TryFromValue, attributes(dxr))]
 pub fn try_from_value(input: TokenStream) -> TokenStream {
-    let mut input = parse_macro_input!(input as DeriveInput);
-
-    let name = input.ident;
-    let name_str = name.to_string();
-    let dxr = use_dxr();
-
-    for param in &mut input.generics.params {
-        if let GenericParam::Type(ref mut type_param) = *param {
-            type_param.bounds.push(parse_quote!(#dxr::TryFromValue));
-        }
-    }
-
-    let generics = input.generics;
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-    let mut field_impls = Vec::new();
-
-    match &input.data {
-        Data::Struct(data) => {
-            match &data.fields {
-                Fields::Named(fields) => {
-                    for field in &fields.named {
-                        let ident = field.ident.as_ref().expect("Failed to get struct field identifier.");
-                        let stype = match &field.ty {
-                        Type::Path(t) => t.to_token_stream(),
-                        Type::Tuple(t) => t.to_token_stream(),
-                        // syn::Type::Array: fixed-size array
-                        Type::Array(t) => t.to_token_stream(),
-                        // syn::Type::Slice: dynamically-sized array
-                        Type::Slice(_) => return quote_spanned! {
-                            field.ty.span() => compile_error!(
-                                "Deriving TryFromValue is not possible for structs that contain dynamically sized arrays, \
-                                 as they don't implement Sized. Try using a Vec here."
-                            );
-                        }.into(),
-                        Type::Reference(_) => return quote_spanned! {
-                            field.ty.span() => compile_error!(
-                                "Deriving TryFromValue is not possible for structs that contain reference types. \
-                                 Try using a std::borrow::Cow here."
-                            );
-                        }.into(),
-                        _ => return quote_spanned! {
-                            field.ty.span() => compile_error!(
-                                "Deriving TryFromValue is not possible due to an unrecognised struct field type."
-                            );
-                        }.into(),
-                    };
-                        let ident_str = ident.to_string();
-                        let ident_str = match ident_str.strip_prefix("r#") {
-                            Some(s) => s,
-                            None => ident_str.as_str(),
-                        };
-                        field_impls.push(quote! {
-                            #ident: <#stype as TryFromValue>::try_from_value(map.get(#ident_str)
-                                .ok_or_else(|| #dxr::DxrError::missing_field(#name_str, #ident_str))?)?,
-                        });
-                    }
-                },
-                Fields::Unnamed(_) => {
-                    return quote_spanned! {
-                        name.span() => compile_error!(
-                            "Deriving TryFromValue for tuple structs is not supported."
-                        );
-                    }
-                    .into()
-                },
-                Fields::Unit => {
-                    return quote_spanned! {
-                        name.span() => compile_error!(
-                            "Deriving TryFromValue for unit structs is not supported."
-                        );
-                    }
-                    .into()
-                },
-            }
-        },
-        Data::Enum(_) | Data::Union(_) => {
-            return quote_spanned! {
-                name.span() => compile_error!(
-                    "Deriving TryFromValue for enums and unions is not supported."
-                );
-            }
-            .into()
-        },
-    };
-
-    let mut fields = TokenStream2::new();
-    fields.extend(field_impls);
-
-    let impl_block = quote! {
-        impl #impl_generics #dxr::TryFromValue for #name #ty_generics #where_clause {
-            fn try_from_value(value: &#dxr::Value) -> ::std::result::Result<#name #ty_generics, #dxr::DxrError> {
-                use ::std::collections::HashMap;
-                use ::std::string::String;
-                use #dxr::{Value, DxrError};
-
-                let map: HashMap<String, Value> = HashMap::try_from_value(value)?;
-
-                Ok(#name {
-                    #fields
-                })
-            }
-        }
-    };
-
-    TokenStream::from(impl_block)
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(value::try_from_value(input))
 }
 
 /// Procedural macro for deriving an implementation of the `TryToValue` trait for structs.
@@ -159,108 +77,157 @@ pub fn try_from_value(input: TokenStream) -> TokenStream {
 ///
 /// Additionally, non-fixed-size arrays `[T]` (i.e. the struct does not implement `Sized`)
 /// are not supported.
-#[proc_macro_derive(TryToValue)]
+///
+/// The conversion of individual fields can be customized with attributes:
+///
+/// - `#[dxr(to_value = "path")]`: call the function at `path` (with signature
+///   `fn(&T) -> Result<Value, DxrError>`) instead of `<T as TryToValue>::try_to_value`
+/// - `#[dxr(with = "module")]`: shorthand for `to_value = "module::to_value"` (and
+///   `from_value = "module::from_value"` when deriving `TryFromValue`)
+///
+/// The `#[dxr(default)]` attribute for `TryFromValue` has no effect when converting into values,
+/// and the `#[dxr(variadic)]` attribute (which is only supported for method call parameters)
+/// results in a compilation error.
+///
+/// Fields of type `PhantomData<T>` are skipped. Trait bounds on type parameters are inferred, but
+/// they can be overridden with a `#[dxr(bound = "T: Trait")]` attribute on the struct.
+#[proc_macro_derive(TryToValue, attributes(dxr))]
 pub fn try_to_value(input: TokenStream) -> TokenStream {
-    let mut input = parse_macro_input!(input as DeriveInput);
-
-    let name = input.ident;
-    let dxr = use_dxr();
-
-    for param in &mut input.generics.params {
-        if let GenericParam::Type(ref mut type_param) = *param {
-            type_param.bounds.push(parse_quote!(#dxr::TryToValue));
-        }
-    }
-
-    let generics = input.generics;
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-    let mut field_impls = Vec::new();
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(value::try_to_value(input))
+}
 
-    match &input.data {
-        Data::Struct(data) => match &data.fields {
-            Fields::Named(fields) => {
-                for field in &fields.named {
-                    let ident = field.ident.as_ref().expect("Failed to get struct field identifier.");
-                    let stype =
-                        match &field.ty {
-                            Type::Path(t) => t.to_token_stream(),
-                            Type::Tuple(t) => t.to_token_stream(),
-                            Type::Reference(t) => t.to_token_stream(),
-                            // syn::Type::Array: fixed-size array
-                            Type::Array(t) => t.to_token_stream(),
-                            // syn::Type::Slice: dynamically-sized array
-                            Type::Slice(_) => return quote_spanned! {
-                                field.ty.span() => compile_error!(
-                                    "Deriving TryToValue is not possible for structs that contain dynamically sized arrays, \
-                                     as they don't implement Sized. Try using a Vec or slice reference here."
-                                );
-                            }
-                            .into(),
-                            _ => {
-                                return quote_spanned! {
-                                    field.ty.span() => compile_error!(
-                                        "Deriving TryToValue is not possible due to an unrecognised struct field type."
-                                    );
-                                }
-                                .into()
-                            },
-                        };
-                    let ident_str = ident.to_string();
-                    let ident_str = match ident_str.strip_prefix("r#") {
-                        Some(s) => s,
-                        None => ident_str.as_str(),
-                    };
-                    field_impls.push(quote! {
-                        map.insert(String::from(#ident_str), <#stype as TryToValue>::try_to_value(&self.#ident)?);
-                    });
-                }
-            },
-            Fields::Unnamed(_) => {
-                return quote_spanned! {
-                    name.span() => compile_error!(
-                        "Deriving TryToValue for tuple structs is not supported."
-                    );
-                }
-                .into()
-            },
-            Fields::Unit => {
-                return quote_spanned! {
-                    name.span() => compile_error!(
-                        "Deriving TryToValue for unit structs is not supported."
-                    );
-                }
-                .into()
-            },
-        },
-        Data::Enum(_) | Data::Union(_) => {
-            return quote_spanned! {
-                name.span() => compile_error!(
-                    "Deriving TryToValue for enums and unions is not supported."
-                );
-            }
-            .into()
-        },
-    }
+/// Procedural macro for deriving an implementation of the `TryBorrowFromValue` trait for structs.
+///
+/// This works like the derive macro for `TryFromValue`, except that struct fields can also borrow
+/// data from the value they are converted from, for example `&'a str` (for string values),
+/// `&'a [u8]` (for base64 values), `&'a Value`, or other types that implement `TryBorrowFromValue`.
+///
+/// The same `#[dxr(...)]` attributes as for `TryFromValue` are supported.
+#[proc_macro_derive(TryBorrowFromValue, attributes(dxr))]
+pub fn try_borrow_from_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(value::try_borrow_from_value(input))
+}
 
-    let mut fields = TokenStream2::new();
-    fields.extend(field_impls);
+/// Procedural macro for deriving an implementation of the `TryFromParams` trait for structs.
+///
+/// The fields of the struct are mapped onto the list of method call parameters in the order in
+/// which they are declared, i.e. the first field is converted from the first parameter, and so on.
+/// Errors that occur while converting a parameter include the name of the corresponding field.
+///
+/// Trailing fields can be marked as optional - the corresponding parameters can then be omitted:
+///
+/// - fields of type `Option<T>` are set to `None` if the parameter is missing
+/// - fields with a `#[dxr(default)]` attribute are set to their `Default::default()` value
+///
+/// Additionally, the last field can be marked with a `#[dxr(variadic)]` attribute. This field
+/// (usually a `Vec<T>`) is then converted from all remaining parameters.
+///
+/// The `#[dxr(with = "module")]` and `#[dxr(from_value = "path")]` attributes for customizing the
+/// conversion of individual fields are supported in the same way as for `TryFromValue`.
+///
+/// Structs that are used as parameters of typed method handlers also need to derive
+/// `XmlRpcParams`, which describes the types of the parameters.
+///
+/// Deriving this trait for enums, unions, tuple structs, or unit structs is not supported.
+#[proc_macro_derive(TryFromParams, attributes(dxr))]
+pub fn try_from_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(params::try_from_params(input))
+}
 
-    let impl_block = quote! {
-        impl #impl_generics #dxr::TryToValue for #name #ty_generics #where_clause {
-            fn try_to_value(&self) -> ::std::result::Result<#dxr::Value, #dxr::DxrError> {
-                use ::std::collections::HashMap;
-                use ::std::string::String;
-                use #dxr::Value;
+/// Procedural macro for deriving an implementation of the `TryToParams` trait for structs.
+///
+/// The fields of the struct are converted into a list of method call parameters in the order in
+/// which they are declared. This is the inverse of the `TryFromParams` derive macro: trailing
+/// fields of type `Option<T>` that are `None` are omitted from the parameter list, and the items
+/// of a `#[dxr(variadic)]` field are appended to the parameter list. Fields that are `None` but
+/// are followed by other parameters are encoded as `<nil/>` values, which requires the `nil`
+/// feature of the `dxr` crate (without it, the conversion returns an error).
+///
+/// The `#[dxr(with = "module")]` and `#[dxr(to_value = "path")]` attributes for customizing the
+/// conversion of individual fields are supported in the same way as for `TryToValue`.
+///
+/// Deriving this trait for enums, unions, tuple structs, or unit structs is not supported.
+#[proc_macro_derive(TryToParams, attributes(dxr))]
+pub fn try_to_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(params::try_to_params(input))
+}
 
-                let mut map: HashMap<String, Value> = HashMap::new();
+/// Procedural macro for deriving an implementation of the `XmlRpcParams` trait for structs.
+///
+/// This is intended to be used together with the derive macro for `TryFromParams` (i.e. for
+/// structs that are the parameters of typed method handlers), and supports the same `#[dxr(...)]`
+/// attributes. The types of the parameters are the XML-RPC types of the struct fields, in the order
+/// in which they are declared, with the following exceptions:
+///
+/// - the parameters of a `#[dxr(variadic)]` field are checked with the `XmlRpcParams`
+///   implementation of the field type (i.e. `Vec<T>`), but they are not part of the signature
+/// - fields with `#[dxr(with = "module")]` or `#[dxr(from_value = "path")]` attributes are
+///   reported as `any`, and their parameters are not checked
+///
+/// Deriving this trait for enums, unions, tuple structs, or unit structs is not supported.
+#[proc_macro_derive(XmlRpcParams, attributes(dxr))]
+pub fn xmlrpc_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(params::xmlrpc_params(input))
+}
 
-                #fields
+/// Procedural macro for deriving an implementation of the `XmlRpcType` trait for structs.
+///
+/// Structs are represented as XML-RPC `struct` values, so the derived implementation always
+/// reports the `struct` type, independent of the types of the struct fields. It is intended to be
+/// used together with the derive macros for `TryFromValue` and `TryToValue`.
+///
+/// Deriving this trait for enums, unions, tuple structs, or unit structs is not supported.
+#[proc_macro_derive(XmlRpcType)]
+pub fn xmlrpc_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(value::xmlrpc_type(input))
+}
 
-                HashMap::try_to_value(&map)
-            }
-        }
-    };
+/// Procedural macro for deriving an implementation of the `IntoFault` trait for enums.
+///
+/// Every variant of the enum needs a `#[fault(code = ...)]` attribute with the fault code (any
+/// constant expression of type `i32`). The fault message is formatted with the optional
+/// `message = "..."` argument, which can refer to the fields of the variant by name (i.e.
+/// `"Not found: {id}"`) or by index (i.e. `"Not found: {0}"`). If no message is specified, the
+/// `Display` implementation of the enum is used instead.
+///
+/// Named fields with a `#[fault(member)]` attribute are additionally included as struct members of
+/// the fault. Since the conversion into a fault cannot fail, fields that cannot be converted into
+/// XML-RPC values with `TryToValue` are included as `string` values with the conversion error
+/// instead (and converting the fault back with `TryFromFault` then fails with a type mismatch
+/// error, unless the field is a string).
+///
+/// This also implements `From<T> for Fault`, so errors can be converted with the `?` operator.
+///
+/// Deriving this trait for structs or unions is not supported.
+#[proc_macro_derive(IntoFault, attributes(fault))]
+pub fn into_fault(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(fault::into_fault(input))
+}
 
-    TokenStream::from(impl_block)
+/// Procedural macro for deriving an implementation of the `TryFromFault` trait for enums.
+///
+/// This is the inverse of the derive macro for `IntoFault`, and uses the same attributes: faults
+/// are converted into the first variant with a matching `#[fault(code = ...)]` attribute. The
+/// fields of the variant are initialized as follows:
+///
+/// - fields with a `#[fault(member)]` attribute are converted from the struct member of the fault
+///   with the same name (with `TryFromValue`)
+/// - fields with a `#[fault(string)]` attribute are set to the fault message
+/// - all other fields are set to their `Default::default()` value
+///
+/// Faults with unknown fault codes are not converted (i.e. the result is `Ok(None)`), and faults
+/// with missing or invalid struct members result in an error.
+///
+/// Deriving this trait for structs or unions is not supported.
+#[proc_macro_derive(TryFromFault, attributes(fault))]
+pub fn try_from_fault(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(fault::try_from_fault(input))
 }
@@ -0,0 +1,391 @@
+//! implementations of the derive macros for `TryFromParams`, `TryToParams`, and `XmlRpcParams`
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::{quote, quote_spanned, ToTokens};
+
+use syn::spanned::Spanned;
+use syn::{parse_quote, Data, DeriveInput, Field, Fields, FieldsNamed, Ident};
+
+use crate::attrs::{is_option, ContainerAttrs, FieldAttrs};
+use crate::use_dxr;
+
+/// kinds of struct fields that are mapped onto method call parameters
+enum ParamKind {
+    Required,
+    Option,
+    Default,
+    Variadic,
+}
+
+struct Param<'a> {
+    field: &'a Field,
+    ident: &'a Ident,
+    name: String,
+    kind: ParamKind,
+    attrs: FieldAttrs,
+}
+
+fn named_fields<'a>(input: &'a DeriveInput, derive: &str) -> Result<&'a FieldsNamed, TokenStream2> {
+    let name = &input.ident;
+
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields),
+            Fields::Unnamed(_) => {
+                let message = format!("Deriving {derive} for tuple structs is not supported.");
+                Err(quote_spanned! { name.span() => compile_error!(#message); })
+            },
+            Fields::Unit => {
+                let message = format!("Deriving {derive} for unit structs is not supported.");
+                Err(quote_spanned! { name.span() => compile_error!(#message); })
+            },
+        },
+        Data::Enum(_) | Data::Union(_) => {
+            let message = format!("Deriving {derive} for enums and unions is not supported.");
+            Err(quote_spanned! { name.span() => compile_error!(#message); })
+        },
+    }
+}
+
+fn params<'a>(fields: &'a FieldsNamed, derive: &str) -> Result<Vec<Param<'a>>, TokenStream2> {
+    let mut params: Vec<Param> = Vec::new();
+
+    for field in &fields.named {
+        let ident = field.ident.as_ref().expect("Failed to get struct field identifier.");
+        let attrs = FieldAttrs::parse(field, true).map_err(|error| error.to_compile_error())?;
+
+        let ident_str = ident.to_string();
+        let name = match ident_str.strip_prefix("r#") {
+            Some(s) => s.to_owned(),
+            None => ident_str,
+        };
+
+        if let Some(Param {
+            kind: ParamKind::Variadic,
+            ..
+        }) = params.last()
+        {
+            let message = format!("Deriving {derive} is not possible: only the last field can be variadic.");
+            return Err(quote_spanned! { field.span() => compile_error!(#message); });
+        }
+
+        let kind = if attrs.variadic {
+            ParamKind::Variadic
+        } else if attrs.default {
+            ParamKind::Default
+        } else if is_option(&field.ty) {
+            ParamKind::Option
+        } else {
+            ParamKind::Required
+        };
+
+        if let ParamKind::Required = kind {
+            if params
+                .iter()
+                .any(|p| matches!(p.kind, ParamKind::Option | ParamKind::Default))
+            {
+                let message =
+                    format!("Deriving {derive} is not possible: required fields cannot follow optional fields.");
+                return Err(quote_spanned! { field.span() => compile_error!(#message); });
+            }
+        }
+
+        params.push(Param {
+            field,
+            ident,
+            name,
+            kind,
+            attrs,
+        });
+    }
+
+    Ok(params)
+}
+
+pub(crate) fn try_from_params(mut input: DeriveInput) -> TokenStream2 {
+    let dxr = use_dxr();
+
+    let fields = match named_fields(&input, "TryFromParams") {
+        Ok(fields) => fields,
+        Err(error) => return error,
+    };
+
+    let params = match params(fields, "TryFromParams") {
+        Ok(params) => params,
+        Err(error) => return error,
+    };
+
+    let required = params.iter().filter(|p| matches!(p.kind, ParamKind::Required)).count();
+    let positional = params.iter().filter(|p| !matches!(p.kind, ParamKind::Variadic)).count();
+    let variadic = params.iter().any(|p| matches!(p.kind, ParamKind::Variadic));
+
+    let mut field_impls = Vec::new();
+
+    for (index, param) in params.iter().enumerate() {
+        let ident = param.ident;
+        let name = param.name.as_str();
+        let ty = &param.field.ty;
+
+        let convert = match &param.attrs.from_value {
+            Some(from_value) => from_value.to_token_stream(),
+            None => quote! { <#ty as TryFromValue>::try_from_value },
+        };
+
+        let missing = match param.kind {
+            ParamKind::Required => quote! {
+                return Err(DxrError::invalid_parameter(#name, DxrError::parameter_mismatch(values.len(), #required)))
+            },
+            ParamKind::Option => quote! { ::std::option::Option::None },
+            ParamKind::Default => quote! { ::std::default::Default::default() },
+            ParamKind::Variadic => {
+                field_impls.push(quote! {
+                    #ident: <#ty as TryFromParams>::try_from_params(values.get(#index..).unwrap_or_default())
+                        .map_err(|error| DxrError::invalid_parameter(#name, error))?,
+                });
+                continue;
+            },
+        };
+
+        field_impls.push(quote! {
+            #ident: match values.get(#index) {
+                Some(value) => #convert(value)
+                    .map_err(|error| DxrError::invalid_parameter(#name, error))?,
+                None => #missing,
+            },
+        });
+    }
+
+    let length_check = if variadic {
+        quote! {}
+    } else {
+        quote! {
+            if values.len() > #positional {
+                return Err(DxrError::parameter_mismatch(values.len(), #positional));
+            }
+        }
+    };
+
+    let container = match ContainerAttrs::parse(&input.attrs) {
+        Ok(container) => container,
+        Err(error) => return error.to_compile_error(),
+    };
+    container.add_bounds(&mut input.generics, parse_quote!(#dxr::TryFromValue));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #dxr::TryFromParams for #name #ty_generics #where_clause {
+            fn try_from_params(values: &[#dxr::Value]) -> ::std::result::Result<#name #ty_generics, #dxr::DxrError> {
+                use #dxr::{DxrError, TryFromParams, TryFromValue};
+
+                #length_check
+
+                Ok(#name {
+                    #(#field_impls)*
+                })
+            }
+        }
+    }
+}
+
+pub(crate) fn try_to_params(mut input: DeriveInput) -> TokenStream2 {
+    let dxr = use_dxr();
+
+    let fields = match named_fields(&input, "TryToParams") {
+        Ok(fields) => fields,
+        Err(error) => return error,
+    };
+
+    let params = match params(fields, "TryToParams") {
+        Ok(params) => params,
+        Err(error) => return error,
+    };
+
+    let optional = params
+        .iter()
+        .any(|p| matches!(p.kind, ParamKind::Option | ParamKind::Variadic));
+
+    let mut field_impls = Vec::new();
+
+    for param in &params {
+        let ident = param.ident;
+        let ty = &param.field.ty;
+
+        let convert = match &param.attrs.to_value {
+            Some(to_value) => to_value.to_token_stream(),
+            None => quote! { <#ty as TryToValue>::try_to_value },
+        };
+
+        // omitted trailing parameters are tracked by the length of the parameter list, and omitted
+        // parameters that are followed by other parameters are encoded as nil values (if possible)
+        field_impls.push(match param.kind {
+            ParamKind::Required | ParamKind::Default if optional => quote! {
+                params.push(Ok(#convert(&self.#ident)?));
+                len = params.len();
+            },
+            ParamKind::Required | ParamKind::Default => quote! {
+                params.push(#convert(&self.#ident)?);
+            },
+            ParamKind::Option if param.attrs.to_value.is_some() => quote! {
+                params.push(Ok(#convert(&self.#ident)?));
+                if self.#ident.is_some() {
+                    len = params.len();
+                }
+            },
+            ParamKind::Option => {
+                let name = param.name.as_str();
+                quote! {
+                    match &self.#ident {
+                        ::std::option::Option::Some(value) => {
+                            params.push(Ok(TryToValue::try_to_value(value)?));
+                            len = params.len();
+                        },
+                        ::std::option::Option::None => params.push(#dxr::__private::nil_param(#name)),
+                    }
+                }
+            },
+            ParamKind::Variadic => quote! {
+                let rest = <#ty as TryToParams>::try_to_params(&self.#ident)?;
+                if !rest.is_empty() {
+                    params.extend(rest.into_iter().map(Ok));
+                    len = params.len();
+                }
+            },
+        });
+    }
+
+    let (init, finish) = if optional {
+        (
+            quote! {
+                let mut params: Vec<::std::result::Result<Value, #dxr::DxrError>> = Vec::new();
+                let mut len = 0;
+            },
+            quote! {
+                params.truncate(len);
+                params.into_iter().collect()
+            },
+        )
+    } else {
+        (
+            quote! { let mut params: Vec<Value> = Vec::new(); },
+            quote! { Ok(params) },
+        )
+    };
+
+    let container = match ContainerAttrs::parse(&input.attrs) {
+        Ok(container) => container,
+        Err(error) => return error.to_compile_error(),
+    };
+    container.add_bounds(&mut input.generics, parse_quote!(#dxr::TryToValue));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #dxr::TryToParams for #name #ty_generics #where_clause {
+            fn try_to_params(&self) -> ::std::result::Result<::std::vec::Vec<#dxr::Value>, #dxr::DxrError> {
+                use ::std::vec::Vec;
+                use #dxr::{TryToParams, TryToValue, Value};
+
+                #init
+
+                #(#field_impls)*
+
+                #finish
+            }
+        }
+    }
+}
+
+pub(crate) fn xmlrpc_params(mut input: DeriveInput) -> TokenStream2 {
+    let dxr = use_dxr();
+
+    let fields = match named_fields(&input, "XmlRpcParams") {
+        Ok(fields) => fields,
+        Err(error) => return error,
+    };
+
+    let params = match params(fields, "XmlRpcParams") {
+        Ok(params) => params,
+        Err(error) => return error,
+    };
+
+    let positional = params.iter().filter(|p| !matches!(p.kind, ParamKind::Variadic)).count();
+    let variadic = params.iter().any(|p| matches!(p.kind, ParamKind::Variadic));
+
+    let mut type_impls = Vec::new();
+    let mut check_impls = Vec::new();
+
+    for (index, param) in params.iter().enumerate() {
+        let name = param.name.as_str();
+        let ty = &param.field.ty;
+
+        // the types of variadic fields are not part of the signature, but their parameters are checked
+        if let ParamKind::Variadic = param.kind {
+            check_impls.push(quote! {
+                <#ty as XmlRpcParams>::check_params(values.get(#index..).unwrap_or_default())
+                    .map_err(|error| DxrError::invalid_parameter(#name, error))?;
+            });
+            continue;
+        }
+
+        // fields with custom conversions can be converted from values of any type
+        if param.attrs.from_value.is_some() {
+            type_impls.push(quote! { "any" });
+            continue;
+        }
+
+        type_impls.push(quote! { <#ty as XmlRpcType>::xmlrpc_type() });
+        check_impls.push(quote! {
+            if let Some(value) = values.get(#index) {
+                if !<#ty as XmlRpcType>::accepts(value) {
+                    return Err(DxrError::invalid_parameter(
+                        #name,
+                        DxrError::wrong_type(value.type_name(), <#ty as XmlRpcType>::xmlrpc_type()),
+                    ));
+                }
+            }
+        });
+    }
+
+    let length_check = if variadic {
+        quote! {}
+    } else {
+        quote! {
+            if values.len() > #positional {
+                return Err(DxrError::parameter_mismatch(values.len(), #positional));
+            }
+        }
+    };
+
+    let container = match ContainerAttrs::parse(&input.attrs) {
+        Ok(container) => container,
+        Err(error) => return error.to_compile_error(),
+    };
+    container.add_bounds(&mut input.generics, parse_quote!(#dxr::XmlRpcType));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #dxr::XmlRpcParams for #name #ty_generics #where_clause {
+            fn xmlrpc_params() -> ::std::vec::Vec<&'static str> {
+                use #dxr::XmlRpcType;
+
+                ::std::vec![#(#type_impls),*]
+            }
+
+            fn check_params(values: &[#dxr::Value]) -> ::std::result::Result<(), #dxr::DxrError> {
+                use #dxr::{DxrError, XmlRpcParams, XmlRpcType};
+
+                #length_check
+
+                #(#check_impls)*
+
+                Ok(())
+            }
+        }
+    }
+}
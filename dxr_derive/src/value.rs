@@ -0,0 +1,379 @@
+//! implementations of the derive macros for `TryFromValue`, `TryToValue`, and `XmlRpcType`
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+
+use syn::spanned::Spanned;
+use syn::{parse_quote, Data, DeriveInput, Fields, Lifetime, Type};
+
+use crate::attrs::{is_phantom_data, ContainerAttrs, FieldAttrs};
+use crate::use_dxr;
+
+pub(crate) fn try_from_value(input: DeriveInput) -> TokenStream2 {
+    from_value(input, false)
+}
+
+pub(crate) fn try_borrow_from_value(input: DeriveInput) -> TokenStream2 {
+    from_value(input, true)
+}
+
+fn from_value(input: DeriveInput, borrowed: bool) -> TokenStream2 {
+    let name = input.ident;
+    let name_str = name.to_string();
+    let dxr = use_dxr();
+
+    let derive = if borrowed { "TryBorrowFromValue" } else { "TryFromValue" };
+
+    let container = match ContainerAttrs::parse(&input.attrs) {
+        Ok(container) => container,
+        Err(error) => return error.to_compile_error(),
+    };
+
+    // borrowed values can have any lifetime that is outlived by the lifetime of the input value
+    let mut generics = input.generics.clone();
+    if borrowed {
+        let lifetimes: Vec<Lifetime> = generics.lifetimes().map(|param| param.lifetime.clone()).collect();
+        if lifetimes.is_empty() {
+            generics.params.insert(0, parse_quote!('__dxr));
+        } else {
+            generics.params.insert(0, parse_quote!('__dxr: #(#lifetimes)+*));
+        }
+        container.add_bounds(&mut generics, parse_quote!(#dxr::TryBorrowFromValue<'__dxr>));
+    } else {
+        container.add_bounds(&mut generics, parse_quote!(#dxr::TryFromValue));
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut slot_impls = Vec::new();
+    let mut match_impls = Vec::new();
+    let mut field_impls = Vec::new();
+
+    match &input.data {
+        Data::Struct(data) => {
+            match &data.fields {
+                Fields::Named(fields) => {
+                    for (index, field) in fields.named.iter().enumerate() {
+                        let ident = field.ident.as_ref().expect("Failed to get struct field identifier.");
+                        let attrs = match FieldAttrs::parse(field, false) {
+                            Ok(attrs) => attrs,
+                            Err(error) => return error.to_compile_error(),
+                        };
+
+                        // PhantomData fields are not represented in XML-RPC values
+                        if is_phantom_data(&field.ty) {
+                            field_impls.push(quote! {
+                                #ident: ::std::marker::PhantomData,
+                            });
+                            continue;
+                        }
+
+                        let convert = if let Some(from_value) = &attrs.from_value {
+                            from_value.to_token_stream()
+                        } else {
+                            let stype = match &field.ty {
+                                Type::Path(t) => t.to_token_stream(),
+                                Type::Tuple(t) => t.to_token_stream(),
+                                // syn::Type::Array: fixed-size array
+                                Type::Array(t) => t.to_token_stream(),
+                                // syn::Type::Reference: borrowed values
+                                Type::Reference(t) if borrowed => t.to_token_stream(),
+                                // syn::Type::Slice: dynamically-sized array
+                                Type::Slice(_) => {
+                                    let message = format!(
+                                        "Deriving {derive} is not possible for structs that contain dynamically sized \
+                                         arrays, as they don't implement Sized. Try using a Vec here."
+                                    );
+                                    return quote_spanned! { field.ty.span() => compile_error!(#message); };
+                                },
+                                Type::Reference(_) => {
+                                    let message = format!(
+                                        "Deriving {derive} is not possible for structs that contain reference types. \
+                                         Try using a std::borrow::Cow here, or derive TryBorrowFromValue instead."
+                                    );
+                                    return quote_spanned! { field.ty.span() => compile_error!(#message); };
+                                },
+                                _ => {
+                                    let message = format!(
+                                        "Deriving {derive} is not possible due to an unrecognised struct field type."
+                                    );
+                                    return quote_spanned! { field.ty.span() => compile_error!(#message); };
+                                },
+                            };
+                            if borrowed {
+                                quote! { <#stype as #dxr::TryBorrowFromValue<'__dxr>>::try_borrow_from_value }
+                            } else {
+                                quote! { <#stype as #dxr::TryFromValue>::try_from_value }
+                            }
+                        };
+
+                        let ident_str = ident.to_string();
+                        let ident_str = match ident_str.strip_prefix("r#") {
+                            Some(s) => s,
+                            None => ident_str.as_str(),
+                        };
+
+                        let ty = &field.ty;
+
+                        // types like Option<T> provide a fallback for missing values
+                        let missing = if attrs.default {
+                            quote! { ::std::default::Default::default() }
+                        } else if attrs.from_value.is_some() {
+                            quote! { return Err(#dxr::DxrError::missing_field(#name_str, #ident_str)) }
+                        } else {
+                            let fallback = if borrowed {
+                                quote! { <#ty as #dxr::TryBorrowFromValue<'__dxr>>::try_borrow_from_missing() }
+                            } else {
+                                quote! { <#ty as #dxr::TryFromValue>::try_from_missing() }
+                            };
+                            quote! {
+                                match #fallback {
+                                    ::std::option::Option::Some(value) => value,
+                                    ::std::option::Option::None => {
+                                        return Err(#dxr::DxrError::missing_field(#name_str, #ident_str))
+                                    },
+                                }
+                            }
+                        };
+
+                        // every field is decoded into its own slot in a single pass over all struct members
+                        let slot = format_ident!("__dxr_field_{}", index);
+
+                        slot_impls.push(quote! {
+                            let mut #slot: ::std::option::Option<#ty> = ::std::option::Option::None;
+                        });
+                        match_impls.push(quote! {
+                            #ident_str => {
+                                if #slot.is_some() {
+                                    return Err(#dxr::DxrError::duplicate_field(#name_str, #ident_str));
+                                }
+                                #slot = ::std::option::Option::Some(#convert(value)?);
+                            },
+                        });
+                        field_impls.push(quote! {
+                            #ident: match #slot {
+                                ::std::option::Option::Some(value) => value,
+                                ::std::option::Option::None => #missing,
+                            },
+                        });
+                    }
+                },
+                Fields::Unnamed(_) => {
+                    let message = format!("Deriving {derive} for tuple structs is not supported.");
+                    return quote_spanned! { name.span() => compile_error!(#message); };
+                },
+                Fields::Unit => {
+                    let message = format!("Deriving {derive} for unit structs is not supported.");
+                    return quote_spanned! { name.span() => compile_error!(#message); };
+                },
+            }
+        },
+        Data::Enum(_) | Data::Union(_) => {
+            let message = format!("Deriving {derive} for enums and unions is not supported.");
+            return quote_spanned! { name.span() => compile_error!(#message); };
+        },
+    };
+
+    // unknown struct members are ignored
+    let members = if match_impls.is_empty() {
+        quote! {
+            let _ = value.struct_members()?;
+        }
+    } else {
+        quote! {
+            for (member, value) in value.struct_members()? {
+                match member {
+                    #(#match_impls)*
+                    _ => {},
+                }
+            }
+        }
+    };
+
+    let decode = quote! {
+        #(#slot_impls)*
+
+        #members
+
+        Ok(#name {
+            #(#field_impls)*
+        })
+    };
+
+    if borrowed {
+        quote! {
+            impl #impl_generics #dxr::TryBorrowFromValue<'__dxr> for #name #ty_generics #where_clause {
+                fn try_borrow_from_value(
+                    value: &'__dxr #dxr::Value,
+                ) -> ::std::result::Result<#name #ty_generics, #dxr::DxrError> {
+                    #decode
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics #dxr::TryFromValue for #name #ty_generics #where_clause {
+                fn try_from_value(value: &#dxr::Value) -> ::std::result::Result<#name #ty_generics, #dxr::DxrError> {
+                    #decode
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn try_to_value(input: DeriveInput) -> TokenStream2 {
+    let name = input.ident;
+    let dxr = use_dxr();
+
+    let container = match ContainerAttrs::parse(&input.attrs) {
+        Ok(container) => container,
+        Err(error) => return error.to_compile_error(),
+    };
+
+    let mut generics = input.generics;
+    container.add_bounds(&mut generics, parse_quote!(#dxr::TryToValue));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut field_impls = Vec::new();
+
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                for field in &fields.named {
+                    let ident = field.ident.as_ref().expect("Failed to get struct field identifier.");
+                    let attrs = match FieldAttrs::parse(field, false) {
+                        Ok(attrs) => attrs,
+                        Err(error) => return error.to_compile_error(),
+                    };
+
+                    // PhantomData fields are not represented in XML-RPC values
+                    if is_phantom_data(&field.ty) {
+                        continue;
+                    }
+
+                    let convert = if let Some(to_value) = &attrs.to_value {
+                        to_value.to_token_stream()
+                    } else {
+                        let stype = match &field.ty {
+                            Type::Path(t) => t.to_token_stream(),
+                            Type::Tuple(t) => t.to_token_stream(),
+                            Type::Reference(t) => t.to_token_stream(),
+                            // syn::Type::Array: fixed-size array
+                            Type::Array(t) => t.to_token_stream(),
+                            // syn::Type::Slice: dynamically-sized array
+                            Type::Slice(_) => {
+                                return quote_spanned! {
+                                    field.ty.span() => compile_error!(
+                                        "Deriving TryToValue is not possible for structs that contain dynamically sized arrays, \
+                                         as they don't implement Sized. Try using a Vec or slice reference here."
+                                    );
+                                }
+                            },
+                            _ => {
+                                return quote_spanned! {
+                                    field.ty.span() => compile_error!(
+                                        "Deriving TryToValue is not possible due to an unrecognised struct field type."
+                                    );
+                                }
+                            },
+                        };
+                        quote! { <#stype as TryToValue>::try_to_value }
+                    };
+
+                    let ident_str = ident.to_string();
+                    let ident_str = match ident_str.strip_prefix("r#") {
+                        Some(s) => s,
+                        None => ident_str.as_str(),
+                    };
+                    field_impls.push(quote! {
+                        map.insert(String::from(#ident_str), #convert(&self.#ident)?);
+                    });
+                }
+            },
+            Fields::Unnamed(_) => {
+                return quote_spanned! {
+                    name.span() => compile_error!(
+                        "Deriving TryToValue for tuple structs is not supported."
+                    );
+                }
+            },
+            Fields::Unit => {
+                return quote_spanned! {
+                    name.span() => compile_error!(
+                        "Deriving TryToValue for unit structs is not supported."
+                    );
+                }
+            },
+        },
+        Data::Enum(_) | Data::Union(_) => {
+            return quote_spanned! {
+                name.span() => compile_error!(
+                    "Deriving TryToValue for enums and unions is not supported."
+                );
+            }
+        },
+    }
+
+    let mut fields = TokenStream2::new();
+    fields.extend(field_impls);
+
+    quote! {
+        impl #impl_generics #dxr::TryToValue for #name #ty_generics #where_clause {
+            fn try_to_value(&self) -> ::std::result::Result<#dxr::Value, #dxr::DxrError> {
+                use ::std::collections::HashMap;
+                use ::std::string::String;
+                use #dxr::{TryToValue, Value};
+
+                let mut map: HashMap<String, Value> = HashMap::new();
+
+                #fields
+
+                HashMap::try_to_value(&map)
+            }
+        }
+    }
+}
+
+pub(crate) fn xmlrpc_type(input: DeriveInput) -> TokenStream2 {
+    let name = &input.ident;
+    let dxr = use_dxr();
+
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(_) => {},
+            Fields::Unnamed(_) => {
+                return quote_spanned! {
+                    name.span() => compile_error!(
+                        "Deriving XmlRpcType for tuple structs is not supported."
+                    );
+                }
+            },
+            Fields::Unit => {
+                return quote_spanned! {
+                    name.span() => compile_error!(
+                        "Deriving XmlRpcType for unit structs is not supported."
+                    );
+                }
+            },
+        },
+        Data::Enum(_) | Data::Union(_) => {
+            return quote_spanned! {
+                name.span() => compile_error!(
+                    "Deriving XmlRpcType for enums and unions is not supported."
+                );
+            }
+        },
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #dxr::XmlRpcType for #name #ty_generics #where_clause {
+            fn xmlrpc_type() -> &'static str {
+                "struct"
+            }
+        }
+    }
+}
@@ -22,15 +22,21 @@ use std::fmt::{Debug, Formatter};
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use axum::body::Bytes;
 use axum::http::HeaderMap;
 use axum::routing::post;
 use axum::Router;
 
+use dxr::{Capabilities, Fault, TryFromParams, TryToValue, Validation, XmlRpcParams, XmlRpcType};
+
 use thiserror::Error;
 use tokio::net::TcpListener;
 use tokio::sync::Notify;
 
-use crate::{server, Handler, DEFAULT_SERVER_ROUTE};
+use crate::{
+    decode_error_response, register_capabilities, register_introspection, server_with_options, ErrorFaultFn,
+    FaultCodes, Handler, MethodInfo, ServerOptions, TypedHandler, DEFAULT_SERVER_ROUTE,
+};
 
 /// error type for XML-RPC servers
 #[derive(Debug, Error)]
@@ -56,6 +62,10 @@ pub enum ServerError {
 pub struct RouteBuilder {
     path: Cow<'static, str>,
     handlers: HashMap<&'static str, Box<dyn Handler>>,
+    info: HashMap<&'static str, MethodInfo>,
+    introspection: bool,
+    capabilities: bool,
+    options: ServerOptions,
 }
 
 impl Debug for RouteBuilder {
@@ -66,6 +76,9 @@ impl Debug for RouteBuilder {
         f.debug_struct("ServerBuilder")
             .field("path", &self.path)
             .field("handlers", &handler_list)
+            .field("introspection", &self.introspection)
+            .field("capabilities", &self.capabilities)
+            .field("options", &self.options)
             .finish()
     }
 }
@@ -76,6 +89,10 @@ impl RouteBuilder {
         RouteBuilder {
             path: Cow::Borrowed(DEFAULT_SERVER_ROUTE),
             handlers: HashMap::new(),
+            info: HashMap::new(),
+            introspection: false,
+            capabilities: false,
+            options: ServerOptions::default(),
         }
     }
 
@@ -94,12 +111,126 @@ impl RouteBuilder {
         self
     }
 
+    /// method for adding a new method handler with documentation for the introspection API
+    pub fn add_method_with_info(mut self, name: &'static str, handler: Box<dyn Handler>, info: MethodInfo) -> Self {
+        self.handlers.insert(name, handler);
+        self.info.insert(name, info);
+        self
+    }
+
+    /// method for adding a new method handler with typed parameters and return value
+    ///
+    /// The signature of the method is derived from the parameter and return types, and published
+    /// with the introspection API (if enabled). Use [`RouteBuilder::add_method_with_info`] with
+    /// [`TypedHandler::info`] for adding a help text, too.
+    pub fn add_typed_method<P, R, F>(self, name: &'static str, handler: TypedHandler<P, R, F>) -> Self
+    where
+        P: TryFromParams + XmlRpcParams + 'static,
+        R: TryToValue + XmlRpcType + 'static,
+        F: Fn(P, HeaderMap) -> Result<R, Fault> + Send + Sync + 'static,
+    {
+        let info = handler.info();
+        self.add_method_with_info(name, Box::new(handler), info)
+    }
+
+    /// method for enabling or disabling the XML-RPC introspection API (disabled by default)
+    ///
+    /// If enabled, the `system.listMethods`, `system.methodHelp`, and `system.methodSignature`
+    /// methods are registered when building the route (see [`register_introspection`]).
+    pub fn introspection(mut self, enabled: bool) -> Self {
+        self.introspection = enabled;
+        self
+    }
+
+    /// method for enabling or disabling the `system.getCapabilities` method (disabled by default)
+    ///
+    /// If enabled, the method reports the capabilities of the `dxr` crate (see [`Capabilities::new`]),
+    /// fault code interoperability (unless custom fault codes are used), and support for
+    /// "system.multicall" calls and the introspection API (if enabled).
+    pub fn capabilities(mut self, enabled: bool) -> Self {
+        self.capabilities = enabled;
+        self
+    }
+
+    /// method for overriding the default fault codes for errors that are detected by the server
+    ///
+    /// By default, the standard interoperability fault codes are used (see [`FaultCodes`]).
+    pub fn fault_codes(mut self, fault_codes: FaultCodes) -> Self {
+        self.options = self.options.fault_codes(fault_codes);
+        self
+    }
+
+    /// method for setting the level of validation that is applied to requests
+    ///
+    /// See [`ServerOptions::validation`] for details.
+    pub fn validation(mut self, validation: Validation) -> Self {
+        self.options = self.options.validation(validation);
+        self
+    }
+
+    /// method for setting a custom function for converting errors into faults
+    ///
+    /// See [`ServerOptions::error_faults`] for details.
+    pub fn error_faults(mut self, error_faults: ErrorFaultFn) -> Self {
+        self.options = self.options.error_faults(error_faults);
+        self
+    }
+
+    /// method for limiting the number of calls in a "system.multicall" call
+    ///
+    /// See [`ServerOptions::max_multicall_calls`] for details.
+    #[cfg(feature = "multicall")]
+    pub fn max_multicall_calls(mut self, max_calls: usize) -> Self {
+        self.options = self.options.max_multicall_calls(max_calls);
+        self
+    }
+
+    /// method for handling the calls in a "system.multicall" call concurrently
+    ///
+    /// See [`ServerOptions::multicall_concurrency`] for details.
+    #[cfg(feature = "multicall")]
+    pub fn multicall_concurrency(mut self, concurrency: usize) -> Self {
+        self.options = self.options.multicall_concurrency(concurrency);
+        self
+    }
+
     /// build an [`axum::Router`] from the specified route and registered method handlers
-    pub fn build(self) -> Router {
+    pub fn build(mut self) -> Router {
+        if self.capabilities {
+            let mut capabilities = Capabilities::new();
+            if *self.options.codes() == FaultCodes::default() {
+                capabilities = capabilities.faults_interop();
+            }
+            if self.introspection {
+                capabilities = capabilities.introspection();
+            }
+            #[cfg(feature = "multicall")]
+            {
+                capabilities = capabilities.multicall();
+            }
+
+            register_capabilities(&mut self.handlers, capabilities);
+            self.info.entry("system.getCapabilities").or_insert_with(|| {
+                MethodInfo::new()
+                    .help("Return the capabilities (and extensions of XML-RPC) that are supported by the server.")
+                    .signature(&["struct"])
+            });
+        }
+
+        if self.introspection {
+            register_introspection(&mut self.handlers, self.info);
+        }
+
         let handlers = Arc::new(self.handlers);
+        let options = self.options;
         Router::new().route(
             self.path.as_ref(),
-            post(move |headers: HeaderMap, body: String| async move { server(handlers, &body, headers).await }),
+            post(move |headers: HeaderMap, body: Bytes| async move {
+                match dxr::decode_xml(&body) {
+                    Ok(body) => server_with_options(handlers, &body, headers, &options).await,
+                    Err(error) => decode_error_response(error, &options),
+                }
+            }),
         )
     }
 }
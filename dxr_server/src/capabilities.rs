@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+
+use http::HeaderMap;
+
+use dxr::{Capabilities, TryFromParams, TryToValue, Value};
+
+use crate::{Handler, HandlerResult};
+
+/// function for registering the handler of the `system.getCapabilities` method
+///
+/// The method returns the given [`Capabilities`]. When using a [`RouteBuilder`](crate::RouteBuilder),
+/// enable this method with [`RouteBuilder::capabilities`](crate::RouteBuilder::capabilities)
+/// instead, which also reports the capabilities of the server itself.
+pub fn register_capabilities(handlers: &mut HashMap<&'static str, Box<dyn Handler>>, capabilities: Capabilities) {
+    handlers.insert("system.getCapabilities", Box::new(GetCapabilities { capabilities }));
+}
+
+struct GetCapabilities {
+    capabilities: Capabilities,
+}
+
+#[async_trait::async_trait]
+impl Handler for GetCapabilities {
+    async fn handle(&self, params: &[Value], _headers: HeaderMap) -> HandlerResult {
+        <()>::try_from_params(params)?;
+        Ok(self.capabilities.try_to_value()?)
+    }
+}
@@ -1,6 +1,12 @@
+use std::any::type_name;
+use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomData;
+
 use http::HeaderMap;
 
-use dxr::{Fault, Value};
+use dxr::{Fault, TryFromParams, TryToValue, Value, XmlRpcParams, XmlRpcType};
+
+use crate::{ErrorSource, MethodInfo, ServerOptions};
 
 /// type alias for the result type of method handlers
 pub type HandlerResult = Result<Value, Fault>;
@@ -17,6 +23,21 @@ pub trait Handler: Send + Sync {
     /// This method is called for handling incoming XML-RPC method requests with the method name
     /// registered for this [`Handler`], with the request's method parameters as its arguments.
     async fn handle(&self, params: &[Value], headers: HeaderMap) -> HandlerResult;
+
+    /// This method is called by the server instead of [`Handler::handle`], with the options of the
+    /// server as an additional argument.
+    ///
+    /// The default implementation ignores the options, and [`TypedHandler`] uses them for
+    /// converting errors into faults (see [`ServerOptions::error_faults`]).
+    async fn handle_with_options(
+        &self,
+        params: &[Value],
+        headers: HeaderMap,
+        options: &ServerOptions,
+    ) -> HandlerResult {
+        let _ = options;
+        self.handle(params, headers).await
+    }
 }
 
 /// type alias for non-async handler functions without associated data
@@ -28,3 +49,90 @@ impl Handler for HandlerFn {
         self(params, headers)
     }
 }
+
+/// method handler with typed parameters and return values
+///
+/// The types of the parameters (a tuple, the unit type, or a struct that derives both
+/// `TryFromParams` and `XmlRpcParams`) and the return type are known, so
+/// incoming parameters are checked against the expected types (and converted) before the wrapped
+/// function is called, and the signature of the method can be published with the introspection
+/// API (see [`TypedHandler::info`]).
+///
+/// ```
+/// use dxr::Fault;
+/// use dxr_server::TypedHandler;
+/// use http::HeaderMap;
+///
+/// let handler = TypedHandler::new(|(a, b): (i32, i32), _headers: HeaderMap| -> Result<i32, Fault> { Ok(a + b) });
+/// assert_eq!(handler.signature(), vec!["i4", "i4", "i4"]);
+/// ```
+pub struct TypedHandler<P, R, F> {
+    function: F,
+    marker: PhantomData<fn(P) -> R>,
+}
+
+impl<P, R, F> TypedHandler<P, R, F>
+where
+    P: TryFromParams + XmlRpcParams,
+    R: TryToValue + XmlRpcType,
+    F: Fn(P, HeaderMap) -> Result<R, Fault> + Send + Sync,
+{
+    /// constructor for [`TypedHandler`] that wraps a function with typed parameters and return value
+    pub fn new(function: F) -> Self {
+        TypedHandler {
+            function,
+            marker: PhantomData,
+        }
+    }
+
+    /// signature of the method (return type first, followed by parameter types)
+    pub fn signature(&self) -> Vec<&'static str> {
+        let mut signature = vec![R::xmlrpc_type()];
+        signature.extend(P::xmlrpc_params());
+        signature
+    }
+
+    /// documentation of the method for the introspection API (with the signature of the method)
+    ///
+    /// A help text can be added with [`MethodInfo::help`].
+    pub fn info(&self) -> MethodInfo {
+        MethodInfo::new().signature(&self.signature())
+    }
+}
+
+impl<P, R, F> Debug for TypedHandler<P, R, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedHandler")
+            .field("params", &type_name::<P>())
+            .field("returns", &type_name::<R>())
+            .finish()
+    }
+}
+
+#[async_trait::async_trait]
+impl<P, R, F> Handler for TypedHandler<P, R, F>
+where
+    P: TryFromParams + XmlRpcParams,
+    R: TryToValue + XmlRpcType,
+    F: Fn(P, HeaderMap) -> Result<R, Fault> + Send + Sync,
+{
+    async fn handle(&self, params: &[Value], headers: HeaderMap) -> HandlerResult {
+        self.handle_with_options(params, headers, &ServerOptions::default())
+            .await
+    }
+
+    async fn handle_with_options(
+        &self,
+        params: &[Value],
+        headers: HeaderMap,
+        options: &ServerOptions,
+    ) -> HandlerResult {
+        let params_fault = |error| options.error_fault(error, ErrorSource::Params);
+
+        P::check_params(params).map_err(params_fault)?;
+        let params = P::try_from_params(params).map_err(params_fault)?;
+        (self.function)(params, headers)?
+            .try_to_value()
+            .map_err(|error| options.error_fault(error, ErrorSource::ReturnValue))
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use http::HeaderMap;
+
+use dxr::{TryFromParams, TryToValue, Value};
+
+use crate::{Handler, HandlerResult};
+
+/// documentation of a method for the XML-RPC introspection API
+///
+/// The help text is returned by `system.methodHelp`, and the signatures are returned by
+/// `system.methodSignature`. Every signature is a list of XML-RPC type names, starting with the
+/// return type, followed by the types of the parameters (e.g. `["i4", "i4", "i4"]` for a method
+/// that takes two `i4` parameters and returns an `i4`). The type names are the same as the ones
+/// that are reported by [`XmlRpcType`](dxr::XmlRpcType) for signatures of typed method handlers.
+///
+/// ```
+/// use dxr_server::MethodInfo;
+///
+/// let info = MethodInfo::new()
+///     .help("Add two integers.")
+///     .signature(&["i4", "i4", "i4"]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MethodInfo {
+    help: String,
+    signatures: Vec<Vec<String>>,
+}
+
+impl MethodInfo {
+    /// constructor for [`MethodInfo`] without help text or signatures
+    pub fn new() -> Self {
+        MethodInfo::default()
+    }
+
+    /// method for setting the help text of the method
+    pub fn help(mut self, help: &str) -> Self {
+        self.help = help.to_owned();
+        self
+    }
+
+    /// method for adding a signature of the method (return type first, followed by parameter types)
+    ///
+    /// This method can be called multiple times for methods with more than one signature.
+    pub fn signature(mut self, types: &[&str]) -> Self {
+        self.signatures.push(types.iter().map(|t| (*t).to_owned()).collect());
+        self
+    }
+
+    fn signatures_to_value(&self) -> HandlerResult {
+        // the introspection API uses the string "undef" for methods without known signatures
+        if self.signatures.is_empty() {
+            Ok(Value::string(String::from("undef")))
+        } else {
+            Ok(self.signatures.try_to_value()?)
+        }
+    }
+}
+
+/// function for registering the handlers of the XML-RPC introspection API
+///
+/// This adds method handlers for `system.listMethods`, `system.methodHelp`, and
+/// `system.methodSignature`, which use the given [`MethodInfo`] for documenting methods. It needs
+/// to be called after all other method handlers were registered, since the list of methods is not
+/// updated afterwards. When using a [`RouteBuilder`](crate::RouteBuilder), enable introspection
+/// with [`RouteBuilder::introspection`](crate::RouteBuilder::introspection) instead.
+///
+/// Unknown methods have an empty help text and the signature `"undef"` (like methods without
+/// documentation).
+pub fn register_introspection(
+    handlers: &mut HashMap<&'static str, Box<dyn Handler>>,
+    mut info: HashMap<&'static str, MethodInfo>,
+) {
+    info.insert(
+        "system.listMethods",
+        MethodInfo::new()
+            .help("Return the names of all methods that are supported by the server.")
+            .signature(&["array"]),
+    );
+    info.insert(
+        "system.methodHelp",
+        MethodInfo::new()
+            .help("Return the help text of a method.")
+            .signature(&["string", "string"]),
+    );
+    info.insert(
+        "system.methodSignature",
+        MethodInfo::new()
+            .help("Return the list of signatures of a method.")
+            .signature(&["array", "string"]),
+    );
+
+    let mut methods: Vec<&'static str> = handlers
+        .keys()
+        .copied()
+        .chain(["system.listMethods", "system.methodHelp", "system.methodSignature"])
+        .collect();
+
+    #[cfg(feature = "multicall")]
+    {
+        info.entry("system.multicall").or_insert_with(|| {
+            MethodInfo::new()
+                .help("Process a list of method calls and return the list of their results.")
+                .signature(&["array", "array"])
+        });
+        methods.push("system.multicall");
+    }
+
+    methods.sort_unstable();
+    methods.dedup();
+
+    let info = Arc::new(info);
+
+    handlers.insert("system.listMethods", Box::new(ListMethods { methods }));
+    handlers.insert("system.methodHelp", Box::new(MethodHelp { info: info.clone() }));
+    handlers.insert("system.methodSignature", Box::new(MethodSignature { info }));
+}
+
+struct ListMethods {
+    methods: Vec<&'static str>,
+}
+
+#[async_trait::async_trait]
+impl Handler for ListMethods {
+    async fn handle(&self, params: &[Value], _headers: HeaderMap) -> HandlerResult {
+        <()>::try_from_params(params)?;
+        Ok(self.methods.try_to_value()?)
+    }
+}
+
+struct MethodHelp {
+    info: Arc<HashMap<&'static str, MethodInfo>>,
+}
+
+#[async_trait::async_trait]
+impl Handler for MethodHelp {
+    async fn handle(&self, params: &[Value], _headers: HeaderMap) -> HandlerResult {
+        let name: String = String::try_from_params(params)?;
+        let help = self
+            .info
+            .get(name.as_str())
+            .map(|info| info.help.clone())
+            .unwrap_or_default();
+        Ok(Value::string(help))
+    }
+}
+
+struct MethodSignature {
+    info: Arc<HashMap<&'static str, MethodInfo>>,
+}
+
+#[async_trait::async_trait]
+impl Handler for MethodSignature {
+    async fn handle(&self, params: &[Value], _headers: HeaderMap) -> HandlerResult {
+        let name: String = String::try_from_params(params)?;
+        match self.info.get(name.as_str()) {
+            Some(info) => info.signatures_to_value(),
+            None => Ok(Value::string(String::from("undef"))),
+        }
+    }
+}
@@ -6,16 +6,31 @@
 //! This crate provides generic XML-RPC server functionality based on [`dxr`].
 
 use std::collections::HashMap;
+#[cfg(feature = "multicall")]
+use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
 
+#[cfg(feature = "multicall")]
+use futures_util::{stream, FutureExt, StreamExt};
 use http::header::{CONTENT_LENGTH, CONTENT_TYPE};
 use http::{HeaderMap, HeaderValue, StatusCode};
 
-use dxr::{DxrError, Fault, FaultResponse, MethodCall, MethodResponse, Value};
+#[cfg(feature = "multicall")]
+use dxr::DxrError;
+use dxr::{Fault, FaultResponse, MethodCall, MethodResponse, Value, XmlOptions};
 
 mod handler;
 pub use handler::*;
 
+mod capabilities;
+pub use capabilities::register_capabilities;
+
+mod introspection;
+pub use introspection::{register_introspection, MethodInfo};
+
+mod options;
+pub use options::{ErrorFaultFn, ErrorSource, FaultCodes, ServerOptions};
+
 #[cfg(feature = "axum")]
 mod axum_support;
 #[cfg(feature = "axum")]
@@ -40,91 +55,123 @@ pub type HandlerMap = Arc<HashMap<&'static str, Box<dyn Handler>>>;
 /// as arguments, and returns a tuple of HTTP status code [`http::StatusCode`], request
 /// response headers, and response body.
 pub async fn server(handlers: HandlerMap, body: &str, headers: HeaderMap) -> (StatusCode, HeaderMap, String) {
+    server_with_options(handlers, body, headers, &ServerOptions::default()).await
+}
+
+/// This function works like [`server`], but with custom [`ServerOptions`].
+pub async fn server_with_options(
+    handlers: HandlerMap,
+    body: &str,
+    headers: HeaderMap,
+    options: &ServerOptions,
+) -> (StatusCode, HeaderMap, String) {
+    let codes = options.codes();
+
     if headers.get(CONTENT_LENGTH).is_none() {
-        return fault_to_response(411, "Content-Length header missing.");
+        return fault_to_response(codes.missing_content_length_fault());
     }
 
-    let call: MethodCall = match dxr::deserialize_xml(body) {
+    let xml_options = XmlOptions::new().validation(options.validation);
+    let call: MethodCall = match dxr::deserialize_xml_with_options(body, &xml_options) {
         Ok(call) => call,
-        Err(error) => {
-            let e = DxrError::invalid_data(error.to_string());
-            let f = Fault::from(e);
-            return fault_to_response(f.code(), f.string());
-        },
+        Err(error) => return fault_to_response(codes.deserialize_fault(error)),
     };
 
     #[cfg(feature = "multicall")]
     if call.name() == "system.multicall" {
         let calls = match dxr::from_multicall_params(call.params()) {
             Ok(calls) => calls,
-            Err(error) => {
-                let f = Fault::from(error);
-                return fault_to_response(f.code(), f.string());
-            },
+            Err(error) => return fault_to_response(options.error_fault(error, ErrorSource::Params)),
         };
 
-        let mut results = Vec::new();
-
-        for multi in calls {
-            match multi {
-                Ok((name, params)) => {
-                    let handler = match handlers.get(name.as_str()) {
-                        Some(handler) => handler,
-                        None => {
-                            log_no_handler(&name);
-                            results.push(Err(Fault::new(404, String::from("Unknown method."))));
-                            continue;
-                        },
-                    };
-
-                    let result = handler.handle(&params, headers.clone()).await;
-                    results.push(result);
-                },
-                Err(error) => {
-                    results.push(Err(Fault::from(error)));
-                    continue;
-                },
+        if let Some(max_calls) = options.max_multicall_calls {
+            if calls.len() > max_calls {
+                return fault_to_response(codes.invalid_request_fault(&format!(
+                    "Too many calls in system.multicall: {} (maximum: {max_calls})",
+                    calls.len()
+                )));
             }
         }
 
+        // results are collected in the order of calls, even if they are handled concurrently
+        let results: Vec<HandlerResult> = stream::iter(calls)
+            .map(|multi| multicall_inner(&handlers, multi, &headers, options))
+            .buffered(options.multicall_concurrency)
+            .collect()
+            .await;
+
         let value = dxr::into_multicall_response(results);
 
-        return success_to_response(value);
+        return success_to_response(value, options);
     }
 
     let handler = match handlers.get(call.name()) {
         Some(handler) => handler,
         None => {
             log_no_handler(call.name());
-            return fault_to_response(404, "Unknown method.");
+            return fault_to_response(codes.method_not_found_fault());
         },
     };
 
-    let response = match handler.handle(&call.params(), headers).await {
-        Ok(value) => success_to_response(value),
-        Err(fault) => fault_to_response(fault.code(), fault.string()),
+    let response = match handler.handle_with_options(&call.params(), headers, options).await {
+        Ok(value) => success_to_response(value, options),
+        Err(fault) => fault_to_response(fault),
     };
 
     response
 }
 
+#[cfg(feature = "multicall")]
+async fn multicall_inner(
+    handlers: &HandlerMap,
+    multi: Result<(String, Vec<Value>), DxrError>,
+    headers: &HeaderMap,
+    options: &ServerOptions,
+) -> HandlerResult {
+    let codes = options.codes();
+    let (name, params) = multi.map_err(|error| options.error_fault(error, ErrorSource::Params))?;
+
+    if name == "system.multicall" {
+        return Err(codes.invalid_request_fault("Recursive system.multicall calls are not allowed."));
+    }
+
+    let handler = match handlers.get(name.as_str()) {
+        Some(handler) => handler,
+        None => {
+            log_no_handler(&name);
+            return Err(codes.method_not_found_fault());
+        },
+    };
+
+    // a panic in one method handler results in a fault for this call only
+    match AssertUnwindSafe(handler.handle_with_options(&params, headers.clone(), options))
+        .catch_unwind()
+        .await
+    {
+        Ok(result) => result,
+        Err(_) => {
+            log::error!("Method handler panicked: {name}");
+            Err(codes.internal_fault(format!("Method handler panicked: {name}")))
+        },
+    }
+}
+
 fn response_headers() -> HeaderMap {
     let mut headers = HeaderMap::new();
     headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/xml"));
     headers
 }
 
-fn success_to_response(value: Value) -> (StatusCode, HeaderMap, String) {
+fn success_to_response(value: Value, options: &ServerOptions) -> (StatusCode, HeaderMap, String) {
     let response = MethodResponse::new(value);
 
     match dxr::serialize_xml(&response) {
         Ok(success) => (StatusCode::OK, response_headers(), success),
-        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, response_headers(), error.to_string()),
+        Err(error) => fault_to_response(options.codes().internal_fault(error.to_string())),
     }
 }
 
-fn fault_to_response(code: i32, string: &str) -> (StatusCode, HeaderMap, String) {
-    let fault = Fault::new(code, string.to_owned());
+fn fault_to_response(fault: Fault) -> (StatusCode, HeaderMap, String) {
     let response: FaultResponse = fault.into();
 
     match dxr::serialize_xml(&response) {
@@ -133,6 +180,15 @@ fn fault_to_response(code: i32, string: &str) -> (StatusCode, HeaderMap, String)
     }
 }
 
+/// Construct a fault response for a request that could not be decoded.
+#[cfg(feature = "axum")]
+pub(crate) fn decode_error_response(
+    error: dxr::DecodeError,
+    options: &ServerOptions,
+) -> (StatusCode, HeaderMap, String) {
+    fault_to_response(options.codes().decode_fault(error))
+}
+
 /// Write a debug log on missing rpc handler.
 fn log_no_handler(method: &str) {
     log::debug!("No handler registered for method: {method}")
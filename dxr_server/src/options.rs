@@ -0,0 +1,273 @@
+use dxr::{DeError, DxrError, Fault, Validation};
+
+/// Fault codes for errors that are detected by the server itself
+///
+/// By default, the codes from the [specification for fault code interoperability][interop] are
+/// used (see the associated constants of [`Fault`]). Faults that are returned by method handlers
+/// are passed through unchanged.
+///
+/// [interop]: http://xmlrpc-epi.sourceforge.net/specs/rfc.fault_codes.php
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FaultCodes {
+    parse_error: i32,
+    unsupported_encoding: i32,
+    invalid_character: i32,
+    invalid_request: i32,
+    missing_content_length: i32,
+    method_not_found: i32,
+    invalid_params: i32,
+    internal_error: i32,
+}
+
+impl Default for FaultCodes {
+    fn default() -> Self {
+        FaultCodes {
+            parse_error: Fault::PARSE_ERROR,
+            unsupported_encoding: Fault::UNSUPPORTED_ENCODING,
+            invalid_character: Fault::INVALID_CHARACTER,
+            invalid_request: Fault::INVALID_REQUEST,
+            missing_content_length: Fault::INVALID_REQUEST,
+            method_not_found: Fault::METHOD_NOT_FOUND,
+            invalid_params: Fault::INVALID_PARAMS,
+            internal_error: Fault::INTERNAL_ERROR,
+        }
+    }
+}
+
+impl FaultCodes {
+    /// Constructor for [`FaultCodes`] with the standard interoperability fault codes.
+    pub fn new() -> Self {
+        FaultCodes::default()
+    }
+
+    /// Constructor for [`FaultCodes`] with the HTTP-like fault codes that were used by previous
+    /// versions of this crate (`400` for invalid input, `404` for unknown methods, `411` for
+    /// missing `Content-Length` headers, and `500` for internal errors).
+    ///
+    /// Note that this does not affect faults that are returned by method handlers, including
+    /// faults that were converted from a [`DxrError`] with `?` (which use [`Fault::INVALID_PARAMS`]).
+    pub fn legacy() -> Self {
+        FaultCodes {
+            parse_error: 400,
+            unsupported_encoding: 400,
+            invalid_character: 400,
+            invalid_request: 400,
+            missing_content_length: 411,
+            method_not_found: 404,
+            invalid_params: 400,
+            internal_error: 500,
+        }
+    }
+
+    /// Method for setting the fault code for requests that cannot be parsed.
+    pub fn parse_error(mut self, code: i32) -> Self {
+        self.parse_error = code;
+        self
+    }
+
+    /// Method for setting the fault code for requests in an encoding that is not supported.
+    pub fn unsupported_encoding(mut self, code: i32) -> Self {
+        self.unsupported_encoding = code;
+        self
+    }
+
+    /// Method for setting the fault code for requests with data that is not valid in their
+    /// encoding.
+    pub fn invalid_character(mut self, code: i32) -> Self {
+        self.invalid_character = code;
+        self
+    }
+
+    /// Method for setting the fault code for invalid requests (i.e. well-formed XML documents that
+    /// are not valid method calls, or "system.multicall" calls that are rejected).
+    pub fn invalid_request(mut self, code: i32) -> Self {
+        self.invalid_request = code;
+        self
+    }
+
+    /// Method for setting the fault code for requests without a `Content-Length` header.
+    pub fn missing_content_length(mut self, code: i32) -> Self {
+        self.missing_content_length = code;
+        self
+    }
+
+    /// Method for setting the fault code for calls of unknown methods.
+    pub fn method_not_found(mut self, code: i32) -> Self {
+        self.method_not_found = code;
+        self
+    }
+
+    /// Method for setting the fault code for invalid parameters (i.e. parameters of typed method
+    /// handlers or `system.multicall` calls that cannot be converted).
+    pub fn invalid_params(mut self, code: i32) -> Self {
+        self.invalid_params = code;
+        self
+    }
+
+    /// Method for setting the fault code for internal server errors (including return values of
+    /// typed method handlers that cannot be converted).
+    pub fn internal_error(mut self, code: i32) -> Self {
+        self.internal_error = code;
+        self
+    }
+
+    // syntax errors result in "parse error" faults, and well-formed documents that are not valid
+    // method calls result in "invalid request" faults
+    pub(crate) fn deserialize_fault(&self, error: DeError) -> Fault {
+        match error {
+            DeError::InvalidXml(_) | DeError::UnexpectedEof => Fault::new(self.parse_error, error.to_string()),
+            _ => Fault::new(self.invalid_request, error.to_string()),
+        }
+    }
+
+    #[cfg(feature = "axum")]
+    pub(crate) fn decode_fault(&self, error: dxr::DecodeError) -> Fault {
+        match error {
+            dxr::DecodeError::UnsupportedEncoding { .. } => Fault::new(self.unsupported_encoding, error.to_string()),
+            dxr::DecodeError::InvalidCharacter { .. } => Fault::new(self.invalid_character, error.to_string()),
+        }
+    }
+
+    #[cfg(feature = "multicall")]
+    pub(crate) fn invalid_request_fault(&self, message: &str) -> Fault {
+        Fault::new(self.invalid_request, message.to_owned())
+    }
+
+    pub(crate) fn missing_content_length_fault(&self) -> Fault {
+        Fault::new(
+            self.missing_content_length,
+            String::from("Content-Length header missing."),
+        )
+    }
+
+    pub(crate) fn method_not_found_fault(&self) -> Fault {
+        Fault::new(self.method_not_found, String::from("Unknown method."))
+    }
+
+    fn error_fault(&self, error: DxrError, source: ErrorSource) -> Fault {
+        match source {
+            ErrorSource::Params => Fault::new(self.invalid_params, error.to_string()),
+            ErrorSource::ReturnValue => Fault::new(self.internal_error, error.to_string()),
+        }
+    }
+
+    pub(crate) fn internal_fault(&self, message: String) -> Fault {
+        Fault::new(self.internal_error, message)
+    }
+}
+
+/// Origin of conversion errors that are detected by the server
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorSource {
+    /// parameters of typed method handlers, or arguments of "system.multicall" calls
+    Params,
+    /// return values of typed method handlers
+    ReturnValue,
+}
+
+/// function for converting errors that are detected by the server into faults
+///
+/// See [`ServerOptions::error_faults`] for details.
+pub type ErrorFaultFn = fn(DxrError, ErrorSource) -> Fault;
+
+/// Options for XML-RPC servers
+///
+/// With the `multicall` feature, this also includes safeguards for "system.multicall" calls: the
+/// number of calls per request can be limited, and calls can be handled concurrently (up to the
+/// configured limit). Results are always returned in the order of the calls. Nested
+/// "system.multicall" calls are always rejected, and a panic in a method handler only results in
+/// a fault for this call.
+#[derive(Clone, Copy, Debug)]
+pub struct ServerOptions {
+    fault_codes: FaultCodes,
+    error_faults: Option<ErrorFaultFn>,
+    pub(crate) validation: Validation,
+    #[cfg(feature = "multicall")]
+    pub(crate) max_multicall_calls: Option<usize>,
+    #[cfg(feature = "multicall")]
+    pub(crate) multicall_concurrency: usize,
+}
+
+// calls in "system.multicall" calls are handled sequentially by default
+#[cfg_attr(not(feature = "multicall"), allow(clippy::derivable_impls))]
+impl Default for ServerOptions {
+    fn default() -> Self {
+        ServerOptions {
+            fault_codes: FaultCodes::default(),
+            error_faults: None,
+            validation: Validation::default(),
+            #[cfg(feature = "multicall")]
+            max_multicall_calls: None,
+            #[cfg(feature = "multicall")]
+            multicall_concurrency: 1,
+        }
+    }
+}
+
+impl ServerOptions {
+    /// Constructor for [`ServerOptions`] with default settings.
+    pub fn new() -> Self {
+        ServerOptions::default()
+    }
+
+    /// Method for setting the fault codes for errors that are detected by the server.
+    pub fn fault_codes(mut self, fault_codes: FaultCodes) -> Self {
+        self.fault_codes = fault_codes;
+        self
+    }
+
+    /// Method for setting a custom function for converting errors into faults.
+    ///
+    /// By default, errors in the conversion of parameters result in faults with the "invalid
+    /// params" code, and errors in the conversion of return values (which are server errors)
+    /// result in faults with the "internal error" code (see [`FaultCodes`]). This applies to
+    /// typed method handlers ([`TypedHandler`](crate::TypedHandler)) and to "system.multicall"
+    /// calls. Errors that are converted into faults by method handlers themselves (i.e. with
+    /// `?`) are not affected.
+    pub fn error_faults(mut self, error_faults: ErrorFaultFn) -> Self {
+        self.error_faults = Some(error_faults);
+        self
+    }
+
+    /// Method for setting the level of [`Validation`] that is applied to requests (the default is
+    /// [`Validation::Standard`]).
+    ///
+    /// Requests that are rejected are answered with an "invalid request" fault.
+    pub fn validation(mut self, validation: Validation) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    /// Method for limiting the number of calls in a "system.multicall" call (unlimited by default).
+    ///
+    /// Requests with more calls are rejected with an "invalid request" fault.
+    #[cfg(feature = "multicall")]
+    pub fn max_multicall_calls(mut self, max_calls: usize) -> Self {
+        self.max_multicall_calls = Some(max_calls);
+        self
+    }
+
+    /// Method for setting the maximum number of calls in a "system.multicall" call that are
+    /// handled concurrently (the default is one, i.e. calls are handled sequentially).
+    ///
+    /// Note that the calls are handled concurrently within the task that handles the request, and
+    /// are not spawned as separate tasks. This only helps with method handlers that spend their
+    /// time waiting (i.e. for I/O). Method handlers that block the thread (like CPU-bound
+    /// [`HandlerFn`](crate::HandlerFn) functions) are still executed one after the other.
+    #[cfg(feature = "multicall")]
+    pub fn multicall_concurrency(mut self, concurrency: usize) -> Self {
+        self.multicall_concurrency = concurrency.max(1);
+        self
+    }
+
+    pub(crate) fn codes(&self) -> &FaultCodes {
+        &self.fault_codes
+    }
+
+    pub(crate) fn error_fault(&self, error: DxrError, source: ErrorSource) -> Fault {
+        match self.error_faults {
+            Some(error_faults) => error_faults(error, source),
+            None => self.fault_codes.error_fault(error, source),
+        }
+    }
+}
@@ -65,7 +65,7 @@ async fn adder() {
                 Ok(Value::i4(3)),
                 Ok(Value::i4(-8)),
                 Ok(Value::i4(0)),
-                Err(Fault::new(404, String::from("Unknown method.")))
+                Err(Fault::new(Fault::METHOD_NOT_FOUND, String::from("Unknown method.")))
             ]
         ); */
 
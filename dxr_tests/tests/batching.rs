@@ -0,0 +1,175 @@
+//! This file implements tests for the automatic batching of concurrent client calls into
+//! "system.multicall" calls, including the fallback for servers that do not support them (or
+//! that limit the number of calls per "system.multicall" call).
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use dxr::{Fault, FaultResponse, TryFromParams, Value};
+use dxr_client::{BatchOptions, Client, ClientBuilder, ClientError};
+use dxr_server::axum::http::{HeaderMap, StatusCode};
+use dxr_server::axum::routing::post;
+use dxr_server::axum::Router;
+use dxr_server::{server, server_with_options, Handler, HandlerFn, HandlerMap, HandlerResult, Server, ServerOptions};
+use tokio::net::TcpListener;
+
+static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+static MULTICALL: AtomicBool = AtomicBool::new(true);
+static LIMITED_REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+fn add_handler(params: &[Value], _headers: HeaderMap) -> HandlerResult {
+    let (a, b): (i32, i32) = TryFromParams::try_from_params(params)?;
+    Ok(Value::i4(a + b))
+}
+
+// count requests, and reject "system.multicall" calls if they are disabled
+async fn endpoint(headers: HeaderMap, body: String) -> (StatusCode, HeaderMap, String) {
+    REQUESTS.fetch_add(1, Ordering::SeqCst);
+
+    if !MULTICALL.load(Ordering::SeqCst) && body.contains("system.multicall") {
+        let fault = Fault::new(Fault::METHOD_NOT_FOUND, String::from("Unknown method."));
+        let body = dxr::serialize_xml(&FaultResponse::from(fault)).unwrap();
+        return (StatusCode::OK, HeaderMap::new(), body);
+    }
+
+    server(handlers(), &body, headers).await
+}
+
+fn handlers() -> HandlerMap {
+    let mut handlers: HashMap<&'static str, Box<dyn Handler>> = HashMap::new();
+    handlers.insert("add", Box::new(add_handler as HandlerFn));
+    Arc::new(handlers)
+}
+
+// count requests, and reject "system.multicall" calls with more than two calls
+async fn limited_endpoint(headers: HeaderMap, body: String) -> (StatusCode, HeaderMap, String) {
+    LIMITED_REQUESTS.fetch_add(1, Ordering::SeqCst);
+
+    let options = ServerOptions::new().max_multicall_calls(2);
+    server_with_options(handlers(), &body, headers, &options).await
+}
+
+// return invalid responses for "system.multicall" calls
+async fn broken_endpoint(headers: HeaderMap, body: String) -> (StatusCode, HeaderMap, String) {
+    if body.contains("system.multicall") {
+        return (StatusCode::OK, HeaderMap::new(), String::from("<methodResponse>"));
+    }
+
+    server(handlers(), &body, headers).await
+}
+
+// start a server on a free port and return a client for it
+async fn start(route: Router, options: BatchOptions) -> (Arc<Client>, Arc<tokio::sync::Notify>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let mut server = Server::from_route(route);
+    let trigger = server.shutdown_trigger();
+    tokio::spawn(server.serve_listener(listener));
+
+    let client = ClientBuilder::new(format!("http://{addr}/").parse().unwrap())
+        .user_agent("echo-client")
+        .batching(options)
+        .build();
+
+    (Arc::new(client), trigger)
+}
+
+// issue calls from concurrent tasks and check their results
+async fn add_concurrently(client: &Arc<Client>, count: i32) {
+    let tasks: Vec<_> = (0..count)
+        .map(|i| {
+            let client = client.clone();
+            tokio::spawn(async move { client.call::<_, i32>("add", (i, 1)).await })
+        })
+        .collect();
+
+    for (i, task) in tasks.into_iter().enumerate() {
+        assert_eq!(task.await.unwrap().unwrap(), i as i32 + 1);
+    }
+}
+
+#[tokio::test]
+async fn batching() {
+    let route = Router::new().route("/", post(endpoint));
+
+    let mut server = Server::from_route(route);
+    let trigger = server.shutdown_trigger();
+
+    let serve = tokio::spawn(server.serve("0.0.0.0:3000".parse().unwrap()));
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let calls = || async {
+        let client = Arc::new(
+            ClientBuilder::new("http://0.0.0.0:3000/".parse().unwrap())
+                .user_agent("echo-client")
+                .batching(BatchOptions::new().window(Duration::from_millis(100)).max_calls(4))
+                .build(),
+        );
+
+        // concurrent calls are sent in batches of up to four calls
+        add_concurrently(&client, 10).await;
+        assert_eq!(REQUESTS.swap(0, Ordering::SeqCst), 3);
+
+        // faults are returned for the individual calls
+        assert!(client.call::<_, i32>("sub", (1, 2)).await.is_err());
+        REQUESTS.store(0, Ordering::SeqCst);
+
+        // calls are sent individually if "system.multicall" is not supported
+        MULTICALL.store(false, Ordering::SeqCst);
+        add_concurrently(&client, 4).await;
+        assert_eq!(REQUESTS.swap(0, Ordering::SeqCst), 5);
+
+        // batching stays disabled afterwards
+        add_concurrently(&client, 2).await;
+        assert_eq!(REQUESTS.load(Ordering::SeqCst), 2);
+    };
+
+    tokio::spawn(calls()).await.unwrap();
+
+    trigger.notify_one();
+    serve.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn batching_limited() {
+    let route = Router::new().route("/", post(limited_endpoint));
+    let options = BatchOptions::new().window(Duration::from_millis(100)).max_calls(4);
+    let (client, trigger) = start(route, options).await;
+
+    // the rejected batch is sent individually, and the batch size is halved
+    add_concurrently(&client, 4).await;
+    assert_eq!(LIMITED_REQUESTS.swap(0, Ordering::SeqCst), 5);
+
+    // batching stays enabled with smaller batches
+    add_concurrently(&client, 4).await;
+    assert_eq!(LIMITED_REQUESTS.load(Ordering::SeqCst), 2);
+
+    trigger.notify_one();
+}
+
+#[tokio::test]
+async fn batching_errors() {
+    let route = Router::new().route("/", post(broken_endpoint));
+    let options = BatchOptions::new().window(Duration::from_millis(100));
+    let (client, trigger) = start(route, options).await;
+
+    let tasks: Vec<_> = (0..2)
+        .map(|i| {
+            let client = client.clone();
+            tokio::spawn(async move { client.call::<_, i32>("add", (i, 1)).await })
+        })
+        .collect();
+
+    // the original error is shared by all calls in the batch
+    for task in tasks {
+        match task.await.unwrap().unwrap_err() {
+            ClientError::Batch { error } => assert!(matches!(*error, ClientError::RPC { .. })),
+            error => panic!("Unexpected error: {error}"),
+        }
+    }
+
+    trigger.notify_one();
+}
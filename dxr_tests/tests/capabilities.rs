@@ -0,0 +1,88 @@
+//! This file implements tests for the `system.getCapabilities` method, and for the capabilities
+//! that are reported depending on how the server is configured.
+
+use std::sync::Arc;
+
+use dxr::{Capabilities, Fault, TryFromParams, Value};
+use dxr_client::{Client, ClientBuilder, ClientError};
+use dxr_server::axum::http::HeaderMap;
+use dxr_server::axum::Router;
+use dxr_server::{FaultCodes, HandlerFn, HandlerResult, RouteBuilder, Server};
+use tokio::net::TcpListener;
+
+fn add_handler(params: &[Value], _headers: HeaderMap) -> HandlerResult {
+    let (a, b): (i32, i32) = TryFromParams::try_from_params(params)?;
+    Ok(Value::i4(a + b))
+}
+
+fn route() -> RouteBuilder {
+    RouteBuilder::new()
+        .set_path("/")
+        .add_method("add", Box::new(add_handler as HandlerFn))
+        .capabilities(true)
+}
+
+// start a server on a free port, and return a client for it
+async fn start(route: Router) -> (Client, Arc<tokio::sync::Notify>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let mut server = Server::from_route(route);
+    let trigger = server.shutdown_trigger();
+    tokio::spawn(server.serve_listener(listener));
+
+    let client = ClientBuilder::new(format!("http://{addr}/").parse().unwrap())
+        .user_agent("echo-client")
+        .build();
+
+    (client, trigger)
+}
+
+#[tokio::test]
+async fn capabilities_without_introspection() {
+    let (client, trigger) = start(route().build()).await;
+
+    let capabilities = client.capabilities().await.unwrap();
+    assert_eq!(capabilities, Capabilities::new().faults_interop().multicall());
+    assert!(!capabilities.supports(Capabilities::INTROSPECTION));
+
+    // introspection methods are not registered
+    match client
+        .call::<_, Vec<String>>("system.listMethods", ())
+        .await
+        .unwrap_err()
+    {
+        ClientError::Fault { fault } => assert_eq!(fault.code(), Fault::METHOD_NOT_FOUND),
+        error => panic!("unexpected error: {error}"),
+    }
+
+    trigger.notify_one();
+}
+
+#[tokio::test]
+async fn capabilities_with_legacy_fault_codes() {
+    let (client, trigger) = start(route().fault_codes(FaultCodes::legacy()).build()).await;
+
+    let capabilities = client.capabilities().await.unwrap();
+    assert_eq!(capabilities, Capabilities::new().multicall());
+    assert!(!capabilities.supports(Capabilities::FAULTS_INTEROP));
+
+    trigger.notify_one();
+}
+
+#[tokio::test]
+async fn capabilities_with_custom_fault_codes() {
+    let codes = FaultCodes::new().method_not_found(-32000);
+    let (client, trigger) = start(route().fault_codes(codes).build()).await;
+
+    let capabilities = client.capabilities().await.unwrap();
+    assert_eq!(capabilities, Capabilities::new().multicall());
+    assert!(!capabilities.supports(Capabilities::FAULTS_INTEROP));
+
+    match client.call::<_, i32>("sub", (1, 2)).await.unwrap_err() {
+        ClientError::Fault { fault } => assert_eq!(fault.code(), -32000),
+        error => panic!("unexpected error: {error}"),
+    }
+
+    trigger.notify_one();
+}
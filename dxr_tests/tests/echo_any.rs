@@ -3,10 +3,11 @@
 
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use dxr::{DxrError, TryFromValue, TryToParams, TryToValue, Value};
-use dxr_client::{ClientBuilder, ClientError};
+use dxr_client::{ClientBuilder, ClientError, MulticallOptions};
 use dxr_server::{axum::http::HeaderMap, HandlerFn, HandlerResult, RouteBuilder, Server};
 
 use chrono::{NaiveDateTime, SubsecRound, Utc};
@@ -247,6 +248,29 @@ async fn echo_any() {
                 Ok(vec![0, 1, 2].try_to_value().unwrap()),
             ],
         );
+
+        // multicall with heterogeneous parameter and return types
+        let mut multicall = client.multicall_builder();
+        let a = multicall.add::<(i32,)>("echo", -12i32);
+        let b = multicall.add::<(String, bool)>("echo", ("hello", true));
+        let c = multicall.add::<i32>("unknown", ());
+        let results = multicall.send().await.unwrap();
+        assert_eq!(results.get(a).unwrap(), (-12,));
+        assert_eq!(results.get(b).unwrap(), (String::from("hello"), true));
+        assert!(matches!(results.get(c).unwrap_err(), ClientError::Fault { .. }));
+
+        // multicall split into chunks that are sent concurrently
+        let calls: Vec<(String, i32)> = (0..10).map(|i| (String::from("echo"), i)).collect();
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let reported = progress.clone();
+        let options = MulticallOptions::new()
+            .chunk_size(4)
+            .concurrency(2)
+            .progress(move |done, total| reported.lock().unwrap().push((done, total)));
+        let r = client.multicall_with_options(calls, &options).await.unwrap();
+        let expected: Vec<_> = (0..10).map(|i| Ok(vec![i].try_to_value().unwrap())).collect();
+        assert_eq!(r, expected);
+        assert_eq!(*progress.lock().unwrap(), vec![(4, 10), (8, 10), (10, 10)]);
     };
 
     tokio::spawn(calls()).await.unwrap();
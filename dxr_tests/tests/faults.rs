@@ -0,0 +1,273 @@
+//! This file implements tests for the fault codes that are returned by servers for errors that
+//! are detected by the server itself, and for errors that are returned by method handlers
+//! (including typed errors that are converted with the `IntoFault` and `TryFromFault` traits).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use dxr::{DxrError, Fault, FaultResponse, IntoFault, TryFromFault, TryFromParams, TryToValue, Value, XmlRpcType};
+use dxr_client::{ClientBuilder, ClientError};
+use dxr_server::axum::http::header::CONTENT_LENGTH;
+use dxr_server::axum::http::{HeaderMap, HeaderValue};
+use dxr_server::{
+    server_with_options, ErrorSource, FaultCodes, Handler, HandlerFn, HandlerMap, HandlerResult, RouteBuilder, Server,
+    ServerOptions, TypedHandler,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+fn add_handler(params: &[Value], _headers: HeaderMap) -> HandlerResult {
+    let (a, b): (i32, i32) = TryFromParams::try_from_params(params)?;
+    Ok(Value::i4(a + b))
+}
+
+#[derive(Debug, IntoFault, PartialEq, TryFromFault)]
+enum BuildError {
+    #[fault(code = 1001, message = "Build not found: {id}")]
+    NotFound {
+        #[fault(member)]
+        id: i32,
+    },
+}
+
+fn build_handler(params: &[Value], _headers: HeaderMap) -> HandlerResult {
+    let id: i32 = TryFromParams::try_from_params(params)?;
+    Err(BuildError::NotFound { id })?
+}
+
+// return value that cannot be converted into a value
+struct Unencodable;
+
+impl TryToValue for Unencodable {
+    fn try_to_value(&self) -> Result<Value, DxrError> {
+        Err(DxrError::invalid_data(String::from("Unencodable")))
+    }
+}
+
+impl XmlRpcType for Unencodable {
+    fn xmlrpc_type() -> &'static str {
+        "string"
+    }
+}
+
+fn handlers() -> HandlerMap {
+    let mut handlers: HashMap<&'static str, Box<dyn Handler>> = HashMap::new();
+    handlers.insert("add", Box::new(add_handler as HandlerFn));
+    handlers.insert(
+        "typed_add",
+        Box::new(TypedHandler::new(|(a, b): (i32, i32), _headers: HeaderMap| Ok(a + b))),
+    );
+    handlers.insert(
+        "unencodable",
+        Box::new(TypedHandler::new(|(): (), _headers: HeaderMap| Ok(Unencodable))),
+    );
+    Arc::new(handlers)
+}
+
+async fn fault(body: &str, headers: HeaderMap, options: &ServerOptions) -> Fault {
+    let (_, _, body) = server_with_options(handlers(), body, headers, options).await;
+    let response: FaultResponse = dxr::deserialize_xml(&body).unwrap();
+    Fault::try_from(response).unwrap()
+}
+
+fn content_length(body: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_LENGTH, HeaderValue::from(body.len()));
+    headers
+}
+
+#[tokio::test]
+async fn server_fault_codes() {
+    let options = ServerOptions::new();
+
+    let body = "<methodCall><methodName>add</methodName>";
+    assert_eq!(
+        fault(body, content_length(body), &options).await.code(),
+        Fault::PARSE_ERROR
+    );
+
+    let body = "<methodCall><methodName>add</methodName></methodCall>";
+    assert_eq!(
+        fault(body, HeaderMap::new(), &options).await.code(),
+        Fault::INVALID_REQUEST
+    );
+
+    // well-formed XML documents that are not valid method calls
+    for body in [
+        "<methodResponse><params/></methodResponse>",
+        "<methodCall><name>add</name></methodCall>",
+        "<methodCall><methodName>add</methodName><params><param><value><i4>x</i4></value></param></params></methodCall>",
+    ] {
+        assert_eq!(
+            fault(body, content_length(body), &options).await.code(),
+            Fault::INVALID_REQUEST,
+            "{body}"
+        );
+    }
+
+    let body = "<methodCall><methodName>sub</methodName></methodCall>";
+    assert_eq!(
+        fault(body, content_length(body), &options).await.code(),
+        Fault::METHOD_NOT_FOUND
+    );
+
+    let body = "<methodCall><methodName>add</methodName></methodCall>";
+    assert_eq!(
+        fault(body, content_length(body), &options).await.code(),
+        Fault::INVALID_PARAMS
+    );
+
+    // return values that cannot be converted are server errors
+    let body = "<methodCall><methodName>unencodable</methodName></methodCall>";
+    assert_eq!(
+        fault(body, content_length(body), &options).await.code(),
+        Fault::INTERNAL_ERROR
+    );
+}
+
+#[tokio::test]
+async fn custom_error_faults() {
+    let options = ServerOptions::new().error_faults(|error, source| match source {
+        ErrorSource::Params => Fault::new(1, format!("Bad parameters: {error}")),
+        ErrorSource::ReturnValue => Fault::new(2, format!("Bad return value: {error}")),
+    });
+
+    let body = "<methodCall><methodName>typed_add</methodName></methodCall>";
+    assert_eq!(fault(body, content_length(body), &options).await.code(), 1);
+
+    // faults that are converted by method handlers themselves are not affected
+    let body = "<methodCall><methodName>add</methodName></methodCall>";
+    assert_eq!(
+        fault(body, content_length(body), &options).await.code(),
+        Fault::INVALID_PARAMS
+    );
+
+    let body = "<methodCall><methodName>unencodable</methodName></methodCall>";
+    let fault = fault(body, content_length(body), &options).await;
+    assert_eq!(fault.code(), 2);
+    assert_eq!(
+        fault.string(),
+        "Bad return value: Failed to parse XML data: Unencodable"
+    );
+}
+
+#[tokio::test]
+async fn legacy_fault_codes() {
+    let options = ServerOptions::new().fault_codes(FaultCodes::legacy());
+
+    let body = "<methodCall><methodName>add</methodName>";
+    assert_eq!(fault(body, content_length(body), &options).await.code(), 400);
+
+    let body = "<methodCall><methodName>add</methodName></methodCall>";
+    assert_eq!(fault(body, HeaderMap::new(), &options).await.code(), 411);
+
+    // well-formed documents that are not method calls are invalid input
+    let body = "<methodResponse><params/></methodResponse>";
+    assert_eq!(fault(body, content_length(body), &options).await.code(), 400);
+
+    let body = "<methodCall><methodName>sub</methodName></methodCall>";
+    assert_eq!(fault(body, content_length(body), &options).await.code(), 404);
+
+    // missing Content-Length headers have a separate fault code
+    let options = ServerOptions::new().fault_codes(FaultCodes::new().missing_content_length(411));
+    let body = "<methodCall><methodName>add</methodName></methodCall>";
+    assert_eq!(fault(body, HeaderMap::new(), &options).await.code(), 411);
+    let body = "<methodResponse><params/></methodResponse>";
+    assert_eq!(
+        fault(body, content_length(body), &options).await.code(),
+        Fault::INVALID_REQUEST
+    );
+}
+
+#[tokio::test]
+async fn custom_fault_codes() {
+    let route = RouteBuilder::new()
+        .set_path("/")
+        .add_method("add", Box::new(add_handler as HandlerFn))
+        .add_method("build", Box::new(build_handler as HandlerFn))
+        .fault_codes(FaultCodes::new().method_not_found(-1))
+        .build();
+
+    let mut server = Server::from_route(route);
+    let trigger = server.shutdown_trigger();
+
+    let serve = tokio::spawn(server.serve("0.0.0.0:3000".parse().unwrap()));
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let calls = || async {
+        let client = ClientBuilder::new("http://0.0.0.0:3000/".parse().unwrap())
+            .user_agent("echo-client")
+            .build();
+
+        match client.call::<_, i32>("sub", (1, 2)).await.unwrap_err() {
+            ClientError::Fault { fault } => assert_eq!(fault.code(), -1),
+            error => panic!("Unexpected error: {error}"),
+        }
+
+        match client.call::<_, i32>("add", ("1", "2")).await.unwrap_err() {
+            ClientError::Fault { fault } => assert_eq!(fault.code(), Fault::INVALID_PARAMS),
+            error => panic!("Unexpected error: {error}"),
+        }
+
+        let error = client.call::<_, i32>("build", 42).await.unwrap_err();
+        assert_eq!(
+            error.fault_as::<BuildError>().unwrap(),
+            Some(BuildError::NotFound { id: 42 })
+        );
+
+        // other faults are not converted
+        let error = client.call::<_, i32>("sub", (1, 2)).await.unwrap_err();
+        assert_eq!(error.fault_as::<BuildError>().unwrap(), None);
+    };
+
+    tokio::spawn(calls()).await.unwrap();
+
+    trigger.notify_one();
+    serve.await.unwrap().unwrap();
+}
+
+// send a request with a raw body, since clients only send UTF-8 encoded documents
+async fn raw_fault(addr: std::net::SocketAddr, body: &[u8]) -> Fault {
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+
+    let head = format!(
+        "POST / HTTP/1.1\r\nHost: {addr}\r\nContent-Type: text/xml\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await.unwrap();
+    stream.write_all(body).await.unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    let (_, body) = response.split_once("\r\n\r\n").unwrap();
+
+    let response: FaultResponse = dxr::deserialize_xml(body).unwrap();
+    Fault::try_from(response).unwrap()
+}
+
+#[tokio::test]
+async fn encoding_fault_codes() {
+    let route = RouteBuilder::new()
+        .add_method("add", Box::new(add_handler as HandlerFn))
+        .build();
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let mut server = Server::from_route(route);
+    let trigger = server.shutdown_trigger();
+    let serve = tokio::spawn(server.serve_listener(listener));
+
+    let body = b"<?xml version=\"1.0\" encoding=\"EBCDIC-FOO\"?><methodCall><methodName>add</methodName></methodCall>";
+    assert_eq!(raw_fault(addr, body).await.code(), Fault::UNSUPPORTED_ENCODING);
+
+    let body = b"<methodCall><methodName>gr\xFC\xDFe</methodName></methodCall>";
+    assert_eq!(raw_fault(addr, body).await.code(), Fault::INVALID_CHARACTER);
+
+    let body = b"<methodCall><methodName>add</methodName>";
+    assert_eq!(raw_fault(addr, body).await.code(), Fault::PARSE_ERROR);
+
+    trigger.notify_one();
+    serve.await.unwrap().unwrap();
+}
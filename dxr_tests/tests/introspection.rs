@@ -0,0 +1,177 @@
+//! This file implements tests for the XML-RPC introspection API (`system.listMethods`,
+//! `system.methodHelp`, and `system.methodSignature`) and the `system.getCapabilities` method.
+
+use std::time::Duration;
+
+use dxr::{Capabilities, Fault, Rest, TryFromParams, Value, XmlRpcParams};
+use dxr_client::{ClientBuilder, ClientError};
+use dxr_server::{axum::http::HeaderMap, HandlerFn, HandlerResult, MethodInfo, RouteBuilder, Server, TypedHandler};
+
+fn add_handler(params: &[Value], _headers: HeaderMap) -> HandlerResult {
+    let (a, b): (i32, i32) = TryFromParams::try_from_params(params)?;
+    Ok(Value::i4(a + b))
+}
+
+fn echo_handler(params: &[Value], _headers: HeaderMap) -> HandlerResult {
+    Ok(Value::string(String::try_from_params(params)?))
+}
+
+fn join((words, separator): (Vec<String>, String), _headers: HeaderMap) -> Result<String, Fault> {
+    Ok(words.join(&separator))
+}
+
+fn concat((first, rest): (String, Rest<String>), _headers: HeaderMap) -> Result<String, Fault> {
+    Ok(rest.iter().fold(first, |acc, s| acc + s))
+}
+
+#[derive(TryFromParams, XmlRpcParams)]
+struct RepeatParams {
+    text: String,
+    #[dxr(default)]
+    count: i32,
+}
+
+fn repeat(params: RepeatParams, _headers: HeaderMap) -> Result<String, Fault> {
+    Ok(params.text.repeat(params.count.max(0) as usize))
+}
+
+#[tokio::test]
+async fn introspection() {
+    let route = RouteBuilder::new()
+        .set_path("/")
+        .add_method_with_info(
+            "add",
+            Box::new(add_handler as HandlerFn),
+            MethodInfo::new()
+                .help("Add two integers.")
+                .signature(&["i4", "i4", "i4"]),
+        )
+        .add_method("echo", Box::new(echo_handler as HandlerFn))
+        .add_typed_method("join", TypedHandler::new(join))
+        .add_typed_method("concat", TypedHandler::new(concat))
+        .add_typed_method("repeat", TypedHandler::new(repeat))
+        .introspection(true)
+        .capabilities(true)
+        .build();
+
+    let mut server = Server::from_route(route);
+    let trigger = server.shutdown_trigger();
+
+    let serve = tokio::spawn(server.serve("0.0.0.0:3000".parse().unwrap()));
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let calls = || async {
+        let client = ClientBuilder::new("http://0.0.0.0:3000/".parse().unwrap())
+            .user_agent("echo-client")
+            .build();
+
+        let methods: Vec<String> = client.call("system.listMethods", ()).await.unwrap();
+        assert_eq!(
+            methods,
+            vec![
+                "add",
+                "concat",
+                "echo",
+                "join",
+                "repeat",
+                "system.getCapabilities",
+                "system.listMethods",
+                "system.methodHelp",
+                "system.methodSignature",
+                "system.multicall",
+            ]
+        );
+
+        let help: String = client.call("system.methodHelp", "add").await.unwrap();
+        assert_eq!(help, "Add two integers.");
+        let help: String = client.call("system.methodHelp", "echo").await.unwrap();
+        assert_eq!(help, "");
+
+        let signatures: Vec<Vec<String>> = client.call("system.methodSignature", "add").await.unwrap();
+        assert_eq!(signatures, vec![vec!["i4", "i4", "i4"]]);
+        let signatures: Vec<Vec<String>> = client
+            .call("system.methodSignature", "system.methodHelp")
+            .await
+            .unwrap();
+        assert_eq!(signatures, vec![vec!["string", "string"]]);
+        let signature: String = client.call("system.methodSignature", "echo").await.unwrap();
+        assert_eq!(signature, "undef");
+
+        // signatures of typed handlers are derived from their parameter and return types
+        let signatures: Vec<Vec<String>> = client.call("system.methodSignature", "join").await.unwrap();
+        assert_eq!(signatures, vec![vec!["string", "array", "string"]]);
+        let joined: String = client.call("join", (vec!["a", "b"], "-")).await.unwrap();
+        assert_eq!(joined, "a-b");
+
+        // trailing parameters are not part of signatures, but they are checked
+        let signatures: Vec<Vec<String>> = client.call("system.methodSignature", "concat").await.unwrap();
+        assert_eq!(signatures, vec![vec!["string", "string"]]);
+        let concatenated: String = client.call("concat", ("a", "b", "c")).await.unwrap();
+        assert_eq!(concatenated, "abc");
+        match client.call::<_, String>("concat", ("a", "b", 1)).await.unwrap_err() {
+            ClientError::Fault { fault } => {
+                assert_eq!(fault.code(), Fault::INVALID_PARAMS);
+                assert_eq!(
+                    fault.string(),
+                    "Invalid parameter '3': Type mismatch: got i4, expected string"
+                );
+            },
+            error => panic!("unexpected error: {error}"),
+        }
+
+        // signatures of handlers with parameter structs are derived from the types of their fields
+        let signatures: Vec<Vec<String>> = client.call("system.methodSignature", "repeat").await.unwrap();
+        assert_eq!(signatures, vec![vec!["string", "string", "i4"]]);
+        let repeated: String = client.call("repeat", ("ab", 2)).await.unwrap();
+        assert_eq!(repeated, "abab");
+        match client.call::<_, String>("repeat", ("ab", "2")).await.unwrap_err() {
+            ClientError::Fault { fault } => {
+                assert_eq!(fault.code(), Fault::INVALID_PARAMS);
+                assert_eq!(
+                    fault.string(),
+                    "Invalid parameter 'count': Type mismatch: got string, expected i4"
+                );
+            },
+            error => panic!("unexpected error: {error}"),
+        }
+
+        // parameters of typed handlers are checked before they are converted
+        match client.call::<_, String>("join", ("a", "-")).await.unwrap_err() {
+            ClientError::Fault { fault } => {
+                assert_eq!(fault.code(), Fault::INVALID_PARAMS);
+                assert_eq!(
+                    fault.string(),
+                    "Invalid parameter '1': Type mismatch: got string, expected array"
+                );
+            },
+            error => panic!("unexpected error: {error}"),
+        }
+
+        let capabilities = client.capabilities().await.unwrap();
+        for name in [
+            Capabilities::XMLRPC,
+            Capabilities::FAULTS_INTEROP,
+            Capabilities::INTROSPECTION,
+            Capabilities::MULTICALL,
+            Capabilities::NIL,
+            Capabilities::I8,
+        ] {
+            assert!(capabilities.supports(name), "{name} is not supported");
+        }
+        assert_eq!(
+            capabilities,
+            Capabilities::new().faults_interop().introspection().multicall()
+        );
+
+        // wrong number of parameters
+        assert!(matches!(
+            client.call::<_, String>("system.methodHelp", ()).await.unwrap_err(),
+            ClientError::Fault { .. }
+        ));
+    };
+
+    tokio::spawn(calls()).await.unwrap();
+
+    trigger.notify_one();
+    serve.await.unwrap().unwrap();
+}
@@ -0,0 +1,124 @@
+//! This file implements tests for the server-side handling of "system.multicall" calls, including
+//! limits on the number of calls, rejection of nested calls, and concurrent execution.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use dxr::{Fault, FaultResponse, MethodCall, MethodResponse, TryFromParams, TryFromValue, Value};
+use dxr_server::axum::http::header::CONTENT_LENGTH;
+use dxr_server::axum::http::{HeaderMap, HeaderValue};
+use dxr_server::{async_trait, server_with_options, Handler, HandlerFn, HandlerMap, HandlerResult, ServerOptions};
+
+fn add_handler(params: &[Value], _headers: HeaderMap) -> HandlerResult {
+    let (a, b): (i32, i32) = TryFromParams::try_from_params(params)?;
+    Ok(Value::i4(a + b))
+}
+
+fn panic_handler(_params: &[Value], _headers: HeaderMap) -> HandlerResult {
+    panic!("Oh no!")
+}
+
+// handler that waits for the given number of milliseconds before returning it
+struct Sleep;
+
+#[async_trait]
+impl Handler for Sleep {
+    async fn handle(&self, params: &[Value], _headers: HeaderMap) -> HandlerResult {
+        let millis: i32 = TryFromParams::try_from_params(params)?;
+        tokio::time::sleep(Duration::from_millis(millis as u64)).await;
+        Ok(Value::i4(millis))
+    }
+}
+
+fn handlers() -> HandlerMap {
+    let mut handlers: HashMap<&'static str, Box<dyn Handler>> = HashMap::new();
+    handlers.insert("add", Box::new(add_handler as HandlerFn));
+    handlers.insert("panic", Box::new(panic_handler as HandlerFn));
+    handlers.insert("sleep", Box::new(Sleep));
+    Arc::new(handlers)
+}
+
+async fn multicall(calls: Vec<(String, Vec<Value>)>, options: &ServerOptions) -> Result<Value, Fault> {
+    let call = MethodCall::new("system.multicall", vec![dxr::into_multicall_params(calls).unwrap()]);
+    let body = dxr::serialize_xml(&call).unwrap();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_LENGTH, HeaderValue::from(body.len()));
+
+    let (_, _, body) = server_with_options(handlers(), &body, headers, options).await;
+    match dxr::deserialize_xml::<MethodResponse>(&body) {
+        Ok(response) => Ok(response.inner()),
+        Err(_) => Err(Fault::try_from(dxr::deserialize_xml::<FaultResponse>(&body).unwrap()).unwrap()),
+    }
+}
+
+// successful results are arrays with a single value, and faults are structs
+fn results(value: Value) -> Vec<Result<Value, Fault>> {
+    let values: Vec<Value> = TryFromValue::try_from_value(&value).unwrap();
+    values
+        .into_iter()
+        .map(|value| match <(Value,)>::try_from_value(&value) {
+            Ok((value,)) => Ok(value),
+            Err(_) => Err(Fault::try_from_value(&value).unwrap()),
+        })
+        .collect()
+}
+
+fn add(a: i32, b: i32) -> (String, Vec<Value>) {
+    (String::from("add"), vec![Value::i4(a), Value::i4(b)])
+}
+
+#[tokio::test]
+async fn max_calls() {
+    let options = ServerOptions::new().max_multicall_calls(2);
+
+    let value = multicall(vec![add(1, 2), add(3, 4)], &options).await.unwrap();
+    assert_eq!(results(value), vec![Ok(Value::i4(3)), Ok(Value::i4(7))]);
+
+    let fault = multicall(vec![add(1, 2), add(3, 4), add(5, 6)], &options)
+        .await
+        .unwrap_err();
+    assert_eq!(fault.code(), Fault::INVALID_REQUEST);
+}
+
+#[tokio::test]
+async fn nested_multicall() {
+    let nested = (
+        String::from("system.multicall"),
+        vec![dxr::into_multicall_params(vec![add(1, 2)]).unwrap()],
+    );
+
+    let value = multicall(vec![add(1, 2), nested], &ServerOptions::new()).await.unwrap();
+    let results = results(value);
+    assert_eq!(results[0], Ok(Value::i4(3)));
+    assert_eq!(results[1].as_ref().unwrap_err().code(), Fault::INVALID_REQUEST);
+}
+
+#[tokio::test]
+async fn panicking_handler() {
+    let calls = vec![add(1, 2), (String::from("panic"), vec![]), add(3, 4)];
+
+    let value = multicall(calls, &ServerOptions::new()).await.unwrap();
+    let results = results(value);
+    assert_eq!(results[0], Ok(Value::i4(3)));
+    assert_eq!(results[1].as_ref().unwrap_err().code(), Fault::INTERNAL_ERROR);
+    assert_eq!(results[2], Ok(Value::i4(7)));
+}
+
+#[tokio::test]
+async fn concurrent_calls() {
+    let options = ServerOptions::new().multicall_concurrency(3);
+    let calls = [300, 200, 100]
+        .into_iter()
+        .map(|millis| (String::from("sleep"), vec![Value::i4(millis)]))
+        .collect();
+
+    let start = Instant::now();
+    let value = multicall(calls, &options).await.unwrap();
+    assert!(start.elapsed() < Duration::from_millis(600));
+    assert_eq!(
+        results(value),
+        vec![Ok(Value::i4(300)), Ok(Value::i4(200)), Ok(Value::i4(100))]
+    );
+}
@@ -0,0 +1,73 @@
+//! This file implements tests for the level of validation that is applied to requests by servers.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use dxr::{Fault, FaultResponse, MethodResponse, TryFromParams, Validation, Value};
+use dxr_server::axum::http::header::CONTENT_LENGTH;
+use dxr_server::axum::http::{HeaderMap, HeaderValue};
+use dxr_server::{server_with_options, Handler, HandlerFn, HandlerMap, HandlerResult, ServerOptions};
+
+fn not_handler(params: &[Value], _headers: HeaderMap) -> HandlerResult {
+    let value: bool = TryFromParams::try_from_params(params)?;
+    Ok(Value::boolean(!value))
+}
+
+fn handlers() -> HandlerMap {
+    let mut handlers: HashMap<&'static str, Box<dyn Handler>> = HashMap::new();
+    handlers.insert("not", Box::new(not_handler as HandlerFn));
+    handlers.insert("not-strict", Box::new(not_handler as HandlerFn));
+    Arc::new(handlers)
+}
+
+async fn call(name: &str, value: &str, options: &ServerOptions) -> Result<Value, Fault> {
+    let body = format!(
+        "<methodCall><methodName>{name}</methodName><params><param><value><boolean>{value}</boolean></value></param></params></methodCall>"
+    );
+
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_LENGTH, HeaderValue::from(body.len()));
+
+    let (_, _, body) = server_with_options(handlers(), &body, headers, options).await;
+    match dxr::deserialize_xml::<MethodResponse>(&body) {
+        Ok(response) => Ok(response.inner()),
+        Err(_) => Err(Fault::try_from(dxr::deserialize_xml::<FaultResponse>(&body).unwrap()).unwrap()),
+    }
+}
+
+#[tokio::test]
+async fn standard() {
+    let options = ServerOptions::new();
+
+    assert_eq!(call("not", "1", &options).await.unwrap(), Value::boolean(false));
+    assert_eq!(call("not", " 0 ", &options).await.unwrap(), Value::boolean(true));
+    assert_eq!(call("not-strict", "1", &options).await.unwrap(), Value::boolean(false));
+    assert_eq!(
+        call("not", "true", &options).await.unwrap_err().code(),
+        Fault::INVALID_REQUEST
+    );
+}
+
+#[tokio::test]
+async fn lenient() {
+    let options = ServerOptions::new().validation(Validation::Lenient);
+
+    assert_eq!(call("not", "1", &options).await.unwrap(), Value::boolean(false));
+    assert_eq!(call("not", "True", &options).await.unwrap(), Value::boolean(false));
+    assert_eq!(call("not", "FALSE", &options).await.unwrap(), Value::boolean(true));
+}
+
+#[tokio::test]
+async fn strict() {
+    let options = ServerOptions::new().validation(Validation::Strict);
+
+    assert_eq!(call("not", "1", &options).await.unwrap(), Value::boolean(false));
+    assert_eq!(
+        call("not", " 0 ", &options).await.unwrap_err().code(),
+        Fault::INVALID_REQUEST
+    );
+    assert_eq!(
+        call("not-strict", "1", &options).await.unwrap_err().code(),
+        Fault::INVALID_REQUEST
+    );
+}